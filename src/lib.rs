@@ -10,6 +10,7 @@ use color_eyre::eyre::{eyre, Error, Result};
 use solutions::*;
 
 mod solutions;
+mod utils;
 
 // should be done with macros or something
 const SOLVERS: &[&dyn Solver] = &[
@@ -21,7 +22,28 @@ pub fn run_solver(day: Day, part: Part, input: &str) -> Result<String> {
     // assert!(day < SOLVERS.len() && day > 0);
     // let day = day - 1;
 
-    SOLVERS[day.index()].run_part(input, part)
+    run(SOLVERS[day.index()], day, part, input)
+}
+
+pub fn is_implemented(day: Day, part: Part) -> bool {
+    SOLVERS[day.index()].is_implemented(part)
+}
+
+fn run(solver: &dyn Solver, day: Day, part: Part, input: &str) -> Result<String> {
+    if !solver.is_implemented(part) {
+        return Err(SolverError::NotImplemented {
+            day: *day,
+            part: part as usize,
+        }
+        .into());
+    }
+    solver.run_part(input, part)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SolverError {
+    #[error("day {day} part {part} is not implemented yet")]
+    NotImplemented { day: usize, part: usize },
 }
 
 pub enum ParsingErrors {
@@ -54,6 +76,11 @@ impl Day {
     pub fn index(&self) -> usize {
         self.0 - 1
     }
+
+    /// every day there is a solver for, in order
+    pub fn all() -> impl Iterator<Item = Day> {
+        (1..=SOLVERS.len()).map(Day)
+    }
 }
 
 impl TryFrom<usize> for Day {
@@ -80,6 +107,13 @@ pub trait Solver {
     fn part_1(&self, input: &str) -> Result<String>;
     fn part_2(&self, input: &str) -> Result<String>;
 
+    /// every solver is assumed to be finished, a new stub has to override this and return
+    /// `false` for its missing parts, otherwise `run_solver` still panics through its `todo!()`
+    /// instead of reporting `SolverError::NotImplemented`
+    fn is_implemented(&self, part: Part) -> bool {
+        true
+    }
+
     fn run_part(&self, input: &str, part: Part) -> Result<String> {
         match part {
             Part::Part1 => self.part_1(input),
@@ -87,3 +121,38 @@ pub trait Solver {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_ok::assert_ok;
+
+    struct Stub;
+
+    impl Solver for Stub {
+        fn part_1(&self, input: &str) -> Result<String> {
+            Ok(input.len().to_string())
+        }
+
+        fn part_2(&self, input: &str) -> Result<String> {
+            unreachable!("guarded by is_implemented")
+        }
+
+        fn is_implemented(&self, part: Part) -> bool {
+            matches!(part, Part::Part1)
+        }
+    }
+
+    #[test]
+    fn not_implemented() {
+        let day = Day(7);
+        assert_eq!("3", assert_ok!(run(&Stub, day, Part::Part1, "abc")));
+
+        let err = run(&Stub, day, Part::Part2, "abc").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<SolverError>(),
+            Some(SolverError::NotImplemented { day: 7, part: 2 })
+        ));
+        assert_eq!("day 7 part 2 is not implemented yet", err.to_string());
+    }
+}
@@ -1,29 +1,176 @@
-use std::path::PathBuf;
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, Instant},
+};
 
-use aoc2023::{run_solver, Day, Part};
+use aoc2023::{is_implemented, run_solver, Day, Part, SolverError};
 use clap::Parser;
+use color_eyre::eyre::{eyre, Error, Result, WrapErr};
+
+/// where inputs are looked up when no path is given, as `input/day_N`
+const INPUT_DIR: &str = "input";
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
-    day: usize,
-    part: usize,
-    path: PathBuf,
+    /// a day from 1 to 25, or `all`
+    day: Selection,
+    /// runs both parts when left out
+    part: Option<usize>,
+    /// defaults to input/day_N
+    path: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Selection {
+    All,
+    Day(Day),
+}
+
+impl FromStr for Selection {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Selection::All),
+            day => {
+                let day: usize = day
+                    .parse()
+                    .map_err(|_| eyre!("expected a day or all, got {day}"))?;
+                Ok(Selection::Day(day.try_into()?))
+            }
+        }
+    }
+}
+
+fn input_path(day: Day) -> PathBuf {
+    Path::new(INPUT_DIR).join(format!("day_{}", *day))
+}
+
+/// the outcome of a single part, as shown in the table
+enum Outcome {
+    Solved(String, Duration),
+    Failed(String),
+    NotImplemented,
+    /// there is no input file for the day
+    Missing,
+}
+
+impl Outcome {
+    fn answer(&self) -> String {
+        match self {
+            Outcome::Solved(answer, _) => answer.clone(),
+            Outcome::Failed(error) => format!("error: {error}"),
+            Outcome::NotImplemented => "not implemented".to_string(),
+            Outcome::Missing => "no input".to_string(),
+        }
+    }
+
+    fn time(&self) -> String {
+        match self {
+            Outcome::Solved(_, time) => format!("{time:.2?}"),
+            _ => String::new(),
+        }
+    }
+}
+
+fn run(day: Day, part: Part, input: &str) -> Outcome {
+    let start = Instant::now();
+    match run_solver(day, part, input) {
+        Ok(answer) => Outcome::Solved(answer, start.elapsed()),
+        Err(error) => match error.downcast_ref::<SolverError>() {
+            Some(SolverError::NotImplemented { .. }) => Outcome::NotImplemented,
+            None => Outcome::Failed(error.to_string()),
+        },
+    }
+}
+
+fn run_table(days: &[Day], parts: &[Part]) {
+    let mut rows = vec![];
+    for &day in days {
+        let mut row = vec![format!("{}", *day)];
+        let path = input_path(day);
+        let input = std::fs::read_to_string(&path);
+        for &part in parts {
+            let outcome = match &input {
+                _ if !is_implemented(day, part) => Outcome::NotImplemented,
+                Ok(input) => run(day, part, input),
+                Err(error) if error.kind() == ErrorKind::NotFound => Outcome::Missing,
+                Err(error) => Outcome::Failed(format!("reading {}: {error}", path.display())),
+            };
+            let (answer, time) = (outcome.answer(), outcome.time());
+            row.extend([answer, time]);
+        }
+        rows.push(row);
+    }
+
+    let mut header = vec!["Day".to_string()];
+    for &part in parts {
+        header.extend([format!("Part {}", part as usize), "Time".to_string()]);
+    }
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            rows.iter()
+                .chain([&header])
+                .map(|r| r[i].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let line = |row: &[String]| {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, &w)| format!("{cell:<w$}"))
+            .collect();
+        println!("{}", cells.join(" | ").trim_end());
+    };
+    line(&header);
+    let rule: Vec<String> = widths.iter().map(|&w| "-".repeat(w)).collect();
+    println!("{}", rule.join("-+-"));
+    for row in rows.iter() {
+        line(row);
+    }
 }
 
 fn main() -> color_eyre::Result<()> {
-    use std::time::Instant;
     let total = Instant::now();
 
     color_eyre::install()?;
     let cli = Cli::parse();
 
-    let day: Day = cli.day.try_into()?;
-    let part: Part = cli.part.try_into()?;
+    let parts = match cli.part {
+        Some(part) => vec![part.try_into()?],
+        None => vec![Part::Part1, Part::Part2],
+    };
+
+    let (day, part) = match (cli.day, &parts[..]) {
+        (Selection::Day(day), &[part]) => (day, part),
+        (selection, _) => {
+            if let Some(path) = cli.path {
+                return Err(eyre!(
+                    "{} is only read for a single day and part, tables use {INPUT_DIR}/day_N",
+                    path.display()
+                ));
+            }
+            let days: Vec<Day> = match selection {
+                Selection::All => Day::all().collect(),
+                Selection::Day(day) => vec![day],
+            };
+            run_table(&days, &parts);
+            println!("Elapsed: {:.2?}", total.elapsed());
+            return Ok(());
+        }
+    };
 
     println!("Day {} Part {}", *day, part as usize);
 
-    let input = std::fs::read_to_string(cli.path)?;
+    let path = cli.path.unwrap_or_else(|| input_path(day));
+    let input =
+        std::fs::read_to_string(&path).wrap_err_with(|| format!("reading {}", path.display()))?;
     let inner = Instant::now();
 
     let result = run_solver(day, part, &input)?;
@@ -1,28 +1,110 @@
 use crate::Solver;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(solve_part_1(input)?.to_string())
     }
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(solve_part_2(input)?.to_string())
     }
 }
 
+const SPELLED: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn solve_part_1(input: &str) -> Result<usize> {
+    input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| calibration_value(l, ascii_digit))
+        .sum()
+}
+
+fn solve_part_2(input: &str) -> Result<usize> {
+    input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| calibration_value(l, any_digit))
+        .sum()
+}
+
+/// combines the first and last digit found in a line,
+/// `digit_at` is asked for a digit at every char offset so overlapping words like "eightwo" both count
+fn calibration_value(line: &str, digit_at: fn(&str) -> Option<usize>) -> Result<usize> {
+    let mut digits = line.char_indices().filter_map(|(i, _)| digit_at(&line[i..]));
+    let first = digits
+        .next()
+        .ok_or_else(|| eyre!("no digit found in line: {line}"))?;
+    let last = digits.next_back().unwrap_or(first);
+    Ok(first * 10 + last)
+}
+
+fn ascii_digit(s: &str) -> Option<usize> {
+    s.chars()
+        .next()
+        .and_then(|c| c.to_digit(10))
+        .map(|d| d as usize)
+}
+
+fn any_digit(s: &str) -> Option<usize> {
+    ascii_digit(s).or_else(|| {
+        SPELLED
+            .iter()
+            .position(|w| s.starts_with(w))
+            .map(|i| i + 1)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use assert_ok::assert_ok;
     const INPUT: &str = r#"1abc2
 pqr3stu8vwx
 a1b2c3d4e5f
 treb7uchet"#;
 
-#[test]
-fn test_parser(){
-    
-}
+    const INPUT_2: &str = r#"two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen"#;
+
+    #[test]
+    fn part_1() {
+        let sum = assert_ok!(solve_part_1(INPUT));
+        assert_eq!(142, sum)
+    }
+
+    #[test]
+    fn part_2() {
+        let sum = assert_ok!(solve_part_2(INPUT_2));
+        assert_eq!(281, sum)
+    }
+
+    #[test]
+    fn overlapping_words() {
+        let v = assert_ok!(calibration_value("eightwo", any_digit));
+        assert_eq!(82, v)
+    }
+
+    #[test]
+    fn no_digit() {
+        assert!(solve_part_1("abc").is_err());
+        assert!(solve_part_2("abc").is_err());
+    }
+
+    #[test]
+    fn non_ascii() {
+        assert_eq!(11, assert_ok!(calibration_value("é1x", ascii_digit)));
+        assert_eq!(21, assert_ok!(calibration_value("twoö1", any_digit)));
+        assert!(solve_part_2("éx").is_err());
+    }
 }
@@ -1,20 +1,247 @@
 use crate::Solver;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        let maze = Maze::parse(input)?;
+        Ok((maze.main_loop()?.len() / 2).to_string())
     }
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_2(&self, input: &str) -> Result<String> {
+        let maze = Maze::parse(input)?;
+        Ok(enclosed_tiles(&maze.main_loop()?).to_string())
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+use Direction::*;
+
+impl Direction {
+    const ALL: [Direction; 4] = [North, East, South, West];
+
+    fn opposite(self) -> Self {
+        match self {
+            North => South,
+            East => West,
+            South => North,
+            West => East,
+        }
+    }
+}
+
+type Position = (usize, usize);
+
+#[derive(Debug)]
+struct Maze {
+    tiles: Vec<Vec<u8>>,
+    start: Position,
+}
+
+impl Maze {
+    fn parse(input: &str) -> Result<Self> {
+        let tiles: Vec<Vec<u8>> = input
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| l.bytes().collect())
+            .collect();
+
+        let start = tiles
+            .iter()
+            .enumerate()
+            .find_map(|(y, row)| row.iter().position(|&t| t == b'S').map(|x| (x, y)))
+            .ok_or_else(|| eyre!("no start tile S in the maze"))?;
+
+        let mut maze = Self { tiles, start };
+        let connected: Vec<Direction> = Direction::ALL
+            .into_iter()
+            .filter(|&d| {
+                maze.neighbor(start, d)
+                    .is_some_and(|n| maze.connections(n).contains(&d.opposite()))
+            })
+            .collect();
+
+        let shape = match connected[..] {
+            [North, South] => b'|',
+            [East, West] => b'-',
+            [North, East] => b'L',
+            [North, West] => b'J',
+            [South, West] => b'7',
+            [East, South] => b'F',
+            _ => {
+                return Err(eyre!(
+                    "S at {:?} connects to {} pipes {:?}, expected exactly 2",
+                    start,
+                    connected.len(),
+                    connected
+                ))
+            }
+        };
+        maze.tiles[start.1][start.0] = shape;
+
+        Ok(maze)
+    }
+
+    fn tile(&self, (x, y): Position) -> u8 {
+        self.tiles[y][x]
+    }
+
+    fn neighbor(&self, (x, y): Position, d: Direction) -> Option<Position> {
+        let (x, y) = match d {
+            North => (x, y.checked_sub(1)?),
+            East => (x + 1, y),
+            South => (x, y + 1),
+            West => (x.checked_sub(1)?, y),
+        };
+        self.tiles.get(y)?.get(x)?;
+        Some((x, y))
+    }
+
+    fn connections(&self, p: Position) -> &'static [Direction] {
+        match self.tile(p) {
+            b'|' => &[North, South],
+            b'-' => &[East, West],
+            b'L' => &[North, East],
+            b'J' => &[North, West],
+            b'7' => &[South, West],
+            b'F' => &[South, East],
+            _ => &[],
+        }
+    }
+
+    /// every tile of the loop through `S`, in walking order
+    fn main_loop(&self) -> Result<Vec<Position>> {
+        let mut path = vec![self.start];
+        let mut heading = self.connections(self.start)[0];
+        let mut current = self.start;
+
+        loop {
+            let next = self
+                .neighbor(current, heading)
+                .filter(|&n| self.connections(n).contains(&heading.opposite()))
+                .ok_or_else(|| {
+                    eyre!("the pipe at {current:?} leads {heading:?} into nothing it connects to")
+                })?;
+            if next == self.start {
+                return Ok(path);
+            }
+            path.push(next);
+            heading = *self
+                .connections(next)
+                .iter()
+                .find(|&&d| d != heading.opposite())
+                .expect("a pipe that connects back has a second end");
+            current = next;
+        }
+    }
+}
+
+/// shoelace formula for the loop's area, then Pick's theorem gives the interior points
+fn enclosed_tiles(path: &[Position]) -> usize {
+    let twice_area = path
+        .iter()
+        .zip(path.iter().cycle().skip(1))
+        .map(|(&(x1, y1), &(x2, y2))| (x1 * y2) as isize - (x2 * y1) as isize)
+        .sum::<isize>()
+        .unsigned_abs();
+
+    (twice_area + 2 - path.len()) / 2
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use assert_ok::assert_ok;
+    use test_case::test_case;
+
+    const SIMPLE: &str = r#".....
+.S-7.
+.|.|.
+.L-J.
+....."#;
+
+    const COMPLEX: &str = r#"..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ..."#;
+
+    const ENCLOSED_1: &str = r#"...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+..........."#;
+
+    const ENCLOSED_2: &str = r#".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ..."#;
+
+    const ENCLOSED_3: &str = r#"FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L"#;
+
+    #[test_case(SIMPLE, 4)]
+    #[test_case(COMPLEX, 8)]
+    fn part_1(input: &str, expected: usize) {
+        let result = assert_ok!(Day.part_1(input));
+        assert_eq!(expected.to_string(), result)
+    }
+
+    #[test_case(ENCLOSED_1, 4)]
+    #[test_case(ENCLOSED_2, 8)]
+    #[test_case(ENCLOSED_3, 10)]
+    fn part_2(input: &str, expected: usize) {
+        let result = assert_ok!(Day.part_2(input));
+        assert_eq!(expected.to_string(), result)
+    }
+
+    #[test]
+    fn start_shape() {
+        let maze = assert_ok!(Maze::parse(COMPLEX));
+        assert_eq!(b'F', maze.tile(maze.start));
+    }
+
+    #[test_case(".S.\n..."; "no connection")]
+    #[test_case("-S-\n.|."; "three connections")]
+    fn bad_start(input: &str) {
+        let err = Maze::parse(input).unwrap_err();
+        assert!(err.to_string().contains("expected exactly 2"));
+    }
+
+    #[test_case("S-.\n|..\nLF7\n.LJ"; "dead end")]
+    #[test_case(".....\n.S-7.\n.|...\n....."; "into the ground")]
+    #[test_case("S7\n|."; "broken corner")]
+    fn broken_loop(input: &str) {
+        let maze = assert_ok!(Maze::parse(input));
+        let err = maze.main_loop().unwrap_err();
+        assert!(err.to_string().contains("into nothing it connects to"));
+        assert!(Day.part_1(input).is_err());
+        assert!(Day.part_2(input).is_err());
+    }
 }
@@ -1,20 +1,96 @@
 use crate::Solver;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(sum_of_distances(input, 2)?.to_string())
     }
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(sum_of_distances(input, 1_000_000)?.to_string())
     }
 }
 
+type Position = (usize, usize);
+
+fn galaxies(input: &str) -> Result<Vec<Position>> {
+    let mut galaxies = vec![];
+    for (y, l) in input.lines().filter(|l| !l.is_empty()).enumerate() {
+        for (x, c) in l.chars().enumerate() {
+            match c {
+                '#' => galaxies.push((x, y)),
+                '.' => {}
+                c => return Err(eyre!("unexpected character {c} at {x},{y}")),
+            }
+        }
+    }
+    Ok(galaxies)
+}
+
+/// every empty row or column counts `factor` times
+fn sum_of_distances(input: &str, factor: usize) -> Result<usize> {
+    let galaxies = galaxies(input)?;
+    let xs = galaxies.iter().map(|&(x, _)| x).collect();
+    let ys = galaxies.iter().map(|&(_, y)| y).collect();
+    Ok(axis_distances(xs, factor) + axis_distances(ys, factor))
+}
+
+/// sum of all pairwise distances along one axis, the manhattan distance splits into both axes
+fn axis_distances(mut coords: Vec<usize>, factor: usize) -> usize {
+    coords.sort_unstable();
+
+    // expand the gaps between occupied coordinates
+    let mut expanded = Vec::with_capacity(coords.len());
+    let mut offset = 0;
+    for (i, &c) in coords.iter().enumerate() {
+        if i > 0 {
+            let empty = (c - coords[i - 1]).saturating_sub(1);
+            offset += empty * (factor - 1);
+        }
+        expanded.push(c + offset);
+    }
+
+    // in sorted order the i-th coordinate is the larger one in exactly i pairs
+    let mut prefix = 0;
+    let mut sum = 0;
+    for (i, &c) in expanded.iter().enumerate() {
+        sum += c * i - prefix;
+        prefix += c;
+    }
+    sum
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use assert_ok::assert_ok;
+    use test_case::test_case;
+
+    const INPUT: &str = r#"...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#....."#;
+
+    #[test_case(2, 374)]
+    #[test_case(10, 1030)]
+    #[test_case(100, 8410)]
+    fn expansion(factor: usize, expected: usize) {
+        let sum = assert_ok!(sum_of_distances(INPUT, factor));
+        assert_eq!(expected, sum)
+    }
+
+    #[test]
+    fn parser() {
+        let galaxies = assert_ok!(galaxies(INPUT));
+        assert_eq!(9, galaxies.len());
+        assert_eq!((3, 0), galaxies[0]);
+    }
 }
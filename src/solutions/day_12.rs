@@ -1,20 +1,148 @@
 use crate::Solver;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
+use rayon::prelude::*;
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(solve(input, 1)?.to_string())
     }
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(solve(input, 5)?.to_string())
     }
 }
 
+#[derive(Debug, Clone)]
+struct Row {
+    springs: Vec<u8>,
+    groups: Vec<usize>,
+}
+
+impl Row {
+    fn parse(input: &str) -> Result<Self> {
+        let (springs, groups) = input
+            .split_once(' ')
+            .ok_or_else(|| eyre!("missing group sizes in row {input}"))?;
+
+        if let Some(c) = springs.chars().find(|c| !".#?".contains(*c)) {
+            return Err(eyre!("unknown spring condition {c} in row {input}"));
+        }
+        let groups = groups
+            .split(',')
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|e| eyre!("invalid group size in row {input}: {e}"))?;
+
+        Ok(Self {
+            springs: springs.bytes().collect(),
+            groups,
+        })
+    }
+
+    /// repeats the springs `times`, separated by `?`, and the groups along with them
+    fn unfold(&self, times: usize) -> Self {
+        let mut springs = self.springs.clone();
+        for _ in 1..times {
+            springs.push(b'?');
+            springs.extend_from_slice(&self.springs);
+        }
+        Self {
+            springs,
+            groups: self.groups.repeat(times),
+        }
+    }
+
+    fn arrangements(&self) -> u64 {
+        let springs = &self.springs;
+        let groups = &self.groups;
+        let (n, m) = (springs.len(), groups.len());
+
+        // ways[i][g]: arrangements of groups[g..] into springs[i..],
+        // one extra row so a group ending right at the last spring can skip its separator
+        let mut ways = vec![vec![0u64; m + 1]; n + 2];
+        ways[n][m] = 1;
+        ways[n + 1][m] = 1;
+
+        for i in (0..n).rev() {
+            for g in (0..=m).rev() {
+                let mut count = 0;
+                if springs[i] != b'#' {
+                    count += ways[i + 1][g];
+                }
+                if springs[i] != b'.' && g < m {
+                    let end = i + groups[g];
+                    let fits = end <= n
+                        && !springs[i..end].contains(&b'.')
+                        && springs.get(end) != Some(&b'#');
+                    if fits {
+                        count += ways[(end + 1).min(n + 1)][g + 1];
+                    }
+                }
+                ways[i][g] = count;
+            }
+        }
+
+        ways[0][0]
+    }
+}
+
+fn solve(input: &str, unfold: usize) -> Result<u64> {
+    let rows: Vec<Row> = input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(Row::parse)
+        .try_collect()?;
+
+    Ok(rows
+        .par_iter()
+        .map(|r| r.unfold(unfold).arrangements())
+        .sum())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use assert_ok::assert_ok;
+    use test_case::test_case;
+
+    const INPUT: &str = r#"???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1"#;
+
+    #[test]
+    fn part_1() {
+        let sum = assert_ok!(solve(INPUT, 1));
+        assert_eq!(21, sum)
+    }
+
+    #[test]
+    fn part_2() {
+        let sum = assert_ok!(solve(INPUT, 5));
+        assert_eq!(525152, sum)
+    }
+
+    #[test_case("???.### 1,1,3", 1, 1)]
+    #[test_case(".??..??...?##. 1,1,3", 4, 16384)]
+    #[test_case("?#?#?#?#?#?#?#? 1,3,1,6", 1, 1)]
+    #[test_case("????.#...#... 4,1,1", 1, 16)]
+    #[test_case("????.######..#####. 1,6,5", 4, 2500)]
+    #[test_case("?###???????? 3,2,1", 10, 506250)]
+    fn rows(line: &str, folded: u64, unfolded: u64) {
+        let row = assert_ok!(Row::parse(line));
+        assert_eq!(folded, row.arrangements());
+        assert_eq!(unfolded, row.unfold(5).arrangements());
+    }
+
+    #[test]
+    fn unfold() {
+        let row = assert_ok!(Row::parse(".# 1"));
+        let row = row.unfold(5);
+        assert_eq!(b".#?.#?.#?.#?.#".to_vec(), row.springs);
+        assert_eq!(vec![1; 5], row.groups);
+    }
 }
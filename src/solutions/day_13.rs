@@ -1,20 +1,123 @@
 use crate::Solver;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(solve(input, 0)?.to_string())
     }
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(solve(input, 1)?.to_string())
     }
 }
 
+/// every row and column packed into a bitmask, `#` being a set bit
+#[derive(Debug)]
+struct Pattern {
+    rows: Vec<u64>,
+    cols: Vec<u64>,
+}
+
+impl Pattern {
+    fn parse(input: &str) -> Result<Self> {
+        let lines: Vec<&str> = input.lines().collect();
+        let width = lines.first().map_or(0, |l| l.len());
+        if width == 0 || width > 64 || lines.len() > 64 {
+            return Err(eyre!("pattern has to be between 1x1 and 64x64:\n{input}"));
+        }
+
+        let mut rows = vec![0; lines.len()];
+        let mut cols = vec![0; width];
+        for (y, l) in lines.iter().enumerate() {
+            if l.len() != width {
+                return Err(eyre!("ragged line {} in pattern:\n{input}", y + 1));
+            }
+            for (x, c) in l.chars().enumerate() {
+                match c {
+                    '#' => {
+                        rows[y] |= 1 << x;
+                        cols[x] |= 1 << y;
+                    }
+                    '.' => {}
+                    c => return Err(eyre!("unexpected character {c} in pattern:\n{input}")),
+                }
+            }
+        }
+
+        Ok(Self { rows, cols })
+    }
+
+    /// columns left of a vertical mirror, or 100 times the rows above a horizontal one
+    fn summarize(&self, smudges: u32) -> Option<usize> {
+        reflection(&self.cols, smudges).or_else(|| reflection(&self.rows, smudges).map(|r| 100 * r))
+    }
+}
+
+/// the number of lines before the mirror, where the mirrored halves differ in exactly `smudges` cells
+fn reflection(lines: &[u64], smudges: u32) -> Option<usize> {
+    (1..lines.len()).find(|&split| {
+        let (before, after) = lines.split_at(split);
+        before
+            .iter()
+            .rev()
+            .zip(after)
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum::<u32>()
+            == smudges
+    })
+}
+
+fn solve(input: &str, smudges: u32) -> Result<usize> {
+    input
+        .split("\n\n")
+        .filter(|p| !p.trim().is_empty())
+        .enumerate()
+        .map(|(i, p)| {
+            Pattern::parse(p.trim())?
+                .summarize(smudges)
+                .ok_or_else(|| eyre!("pattern {} has no line of reflection", i + 1))
+        })
+        .sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use assert_ok::assert_ok;
+
+    const INPUT: &str = r#"#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#"#;
+
+    #[test]
+    fn part_1() {
+        let sum = assert_ok!(solve(INPUT, 0));
+        assert_eq!(405, sum)
+    }
+
+    #[test]
+    fn part_2() {
+        let sum = assert_ok!(solve(INPUT, 1));
+        assert_eq!(400, sum)
+    }
+
+    #[test]
+    fn no_reflection() {
+        let err = solve("#.\n..", 0).unwrap_err();
+        assert!(err.to_string().contains("pattern 1"));
+    }
 }
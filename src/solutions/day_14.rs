@@ -1,20 +1,188 @@
-use crate::Solver;
-use color_eyre::eyre::Result;
+use std::fmt::Display;
+
+use crate::{utils::nth_state, Solver};
+use color_eyre::eyre::{eyre, Result};
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        let mut dish = Dish::parse(input)?;
+        dish.tilt(Direction::North);
+        Ok(dish.north_load().to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        let dish = Dish::parse(input)?;
+        let dish = nth_state(dish, 1_000_000_000, Dish::spin_cycle);
+        Ok(dish.north_load().to_string())
     }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    North,
+    West,
+    South,
+    East,
+}
+
+const ROUND: u8 = b'O';
+const CUBE: u8 = b'#';
+const EMPTY: u8 = b'.';
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Dish {
+    rocks: Vec<Vec<u8>>,
+    width: usize,
+    height: usize,
+}
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+impl Display for Dish {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rocks.iter() {
+            writeln!(f, "{}", String::from_utf8_lossy(row))?;
+        }
+        Ok(())
+    }
+}
+
+impl Dish {
+    fn parse(input: &str) -> Result<Self> {
+        let rocks: Vec<Vec<u8>> = input
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| l.bytes().collect())
+            .collect();
+
+        let width = rocks.first().map_or(0, |r| r.len());
+        let height = rocks.len();
+        if let Some(y) = rocks.iter().position(|r| r.len() != width) {
+            return Err(eyre!("line {} has a different width", y + 1));
+        }
+        if let Some(c) = rocks
+            .iter()
+            .flatten()
+            .find(|&&c| ![ROUND, CUBE, EMPTY].contains(&c))
+        {
+            return Err(eyre!("unexpected character {}", *c as char));
+        }
+
+        Ok(Self {
+            rocks,
+            width,
+            height,
+        })
+    }
+
+    /// the `k`-th cell of `lane`, counted from the edge the rocks roll towards
+    fn coord(&self, direction: Direction, lane: usize, k: usize) -> (usize, usize) {
+        match direction {
+            Direction::North => (lane, k),
+            Direction::South => (lane, self.height - 1 - k),
+            Direction::West => (k, lane),
+            Direction::East => (self.width - 1 - k, lane),
+        }
+    }
+
+    fn tilt(&mut self, direction: Direction) {
+        let (lanes, len) = match direction {
+            Direction::North | Direction::South => (self.width, self.height),
+            Direction::West | Direction::East => (self.height, self.width),
+        };
+
+        for lane in 0..lanes {
+            let mut free = 0;
+            for k in 0..len {
+                let (x, y) = self.coord(direction, lane, k);
+                match self.rocks[y][x] {
+                    CUBE => free = k + 1,
+                    ROUND => {
+                        let (fx, fy) = self.coord(direction, lane, free);
+                        self.rocks[y][x] = EMPTY;
+                        self.rocks[fy][fx] = ROUND;
+                        free += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn spin_cycle(&self) -> Self {
+        let mut next = self.clone();
+        for d in [
+            Direction::North,
+            Direction::West,
+            Direction::South,
+            Direction::East,
+        ] {
+            next.tilt(d);
+        }
+        next
+    }
+
+    fn north_load(&self) -> usize {
+        self.rocks
+            .iter()
+            .enumerate()
+            .map(|(y, row)| row.iter().filter(|&&c| c == ROUND).count() * (self.height - y))
+            .sum()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use crate::utils::find_cycle;
+    use assert_ok::assert_ok;
+
+    const INPUT: &str = r#"O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#...."#;
+
+    const ONE_CYCLE: &str = r#".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....
+"#;
+
+    #[test]
+    fn part_1() {
+        let load = assert_ok!(Day.part_1(INPUT));
+        assert_eq!("136", load)
+    }
+
+    #[test]
+    fn part_2() {
+        let load = assert_ok!(Day.part_2(INPUT));
+        assert_eq!("64", load)
+    }
+
+    #[test]
+    fn spin_cycle() {
+        let dish = assert_ok!(Dish::parse(INPUT));
+        assert_eq!(ONE_CYCLE, dish.spin_cycle().to_string());
+    }
+
+    #[test]
+    fn cycle() {
+        let dish = assert_ok!(Dish::parse(INPUT));
+        let (cycle, _) = find_cycle(dish, Dish::spin_cycle);
+        assert_eq!(3, cycle.start);
+        assert_eq!(7, cycle.period);
+    }
 }
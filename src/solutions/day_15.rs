@@ -1,20 +1,199 @@
+use std::{borrow::Cow, fmt::Display};
+
 use crate::Solver;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        let input = unwrap_lines(input);
+        let sum: usize = steps(&input).map(|s| hash(s) as usize).sum();
+        Ok(sum.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        let input = unwrap_lines(input);
+        let mut boxes = Boxes::default();
+        for s in steps(&input) {
+            boxes.apply(Step::parse(s)?);
+        }
+        Ok(boxes.focusing_power().to_string())
+    }
+}
+
+/// line breaks are ignored wherever they appear, even in the middle of a step
+fn unwrap_lines(input: &str) -> Cow<'_, str> {
+    match input.contains(['\n', '\r']) {
+        true => Cow::Owned(input.replace(['\n', '\r'], "")),
+        false => Cow::Borrowed(input),
     }
+}
+
+/// the comma separated steps of an input without line breaks
+fn steps(input: &str) -> impl Iterator<Item = &str> {
+    input.split(',').filter(|s| !s.is_empty())
+}
+
+fn hash(s: &str) -> u8 {
+    s.bytes()
+        .fold(0u8, |h, c| h.wrapping_add(c).wrapping_mul(17))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step<'a> {
+    Insert(&'a str, u8),
+    Remove(&'a str),
+}
+
+impl<'a> Step<'a> {
+    fn parse(input: &'a str) -> Result<Self> {
+        if let Some(label) = input.strip_suffix('-') {
+            return Ok(Step::Remove(label));
+        }
+        let (label, focal) = input
+            .split_once('=')
+            .ok_or_else(|| eyre!("step {input} is neither an insert nor a removal"))?;
+        let focal = focal
+            .parse()
+            .map_err(|e| eyre!("invalid focal length in step {input}: {e}"))?;
+        Ok(Step::Insert(label, focal))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Lens<'a> {
+    label: &'a str,
+    focal: u8,
+}
+
+/// the 256 boxes, each keeping its lenses in insertion order
+#[derive(Debug)]
+struct Boxes<'a> {
+    boxes: Vec<Vec<Lens<'a>>>,
+}
+
+impl<'a> Default for Boxes<'a> {
+    fn default() -> Self {
+        Self {
+            boxes: vec![vec![]; 256],
+        }
+    }
+}
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+impl<'a> Display for Boxes<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, lenses) in self.boxes.iter().enumerate() {
+            if lenses.is_empty() {
+                continue;
+            }
+            write!(f, "Box {i}:")?;
+            for Lens { label, focal } in lenses.iter() {
+                write!(f, " [{label} {focal}]")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Boxes<'a> {
+    fn lenses(&self, index: u8) -> &[Lens<'a>] {
+        &self.boxes[index as usize]
+    }
+
+    fn apply(&mut self, step: Step<'a>) {
+        match step {
+            Step::Insert(label, focal) => {
+                let lenses = &mut self.boxes[hash(label) as usize];
+                match lenses.iter_mut().find(|l| l.label == label) {
+                    Some(lens) => lens.focal = focal,
+                    None => lenses.push(Lens { label, focal }),
+                }
+            }
+            Step::Remove(label) => {
+                self.boxes[hash(label) as usize].retain(|l| l.label != label);
+            }
+        }
+    }
+
+    fn focusing_power(&self) -> usize {
+        self.boxes
+            .iter()
+            .enumerate()
+            .flat_map(|(b, lenses)| {
+                lenses
+                    .iter()
+                    .enumerate()
+                    .map(move |(slot, l)| (b + 1) * (slot + 1) * l.focal as usize)
+            })
+            .sum()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use assert_ok::assert_ok;
+
+    const INPUT: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
+
+    #[test]
+    fn part_1() {
+        assert_eq!(52, hash("HASH"));
+        let sum = assert_ok!(Day.part_1(INPUT));
+        assert_eq!("1320", sum)
+    }
+
+    #[test]
+    fn part_2() {
+        let power = assert_ok!(Day.part_2(INPUT));
+        assert_eq!("145", power)
+    }
+
+    #[test]
+    fn ignores_newlines() {
+        let sum = assert_ok!(Day.part_1("rn=1,cm-,qp=3,cm=2,\nqp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"));
+        assert_eq!("1320", sum)
+    }
+
+    #[test]
+    fn newline_inside_step() {
+        let input = "rn=1,cm-,qp=3,cm=2,q\np-,pc=4,ot=9,ab=5,pc-,pc=6,ot\r\n=7\n";
+        assert_eq!("1320", assert_ok!(Day.part_1(input)));
+        assert_eq!("145", assert_ok!(Day.part_2(input)));
+    }
+
+    #[test]
+    fn intermediate_states() {
+        let expected = [
+            "Box 0: [rn 1]\n",
+            "Box 0: [rn 1]\n",
+            "Box 0: [rn 1]\nBox 1: [qp 3]\n",
+            "Box 0: [rn 1] [cm 2]\nBox 1: [qp 3]\n",
+            "Box 0: [rn 1] [cm 2]\n",
+            "Box 0: [rn 1] [cm 2]\nBox 3: [pc 4]\n",
+            "Box 0: [rn 1] [cm 2]\nBox 3: [pc 4] [ot 9]\n",
+            "Box 0: [rn 1] [cm 2]\nBox 3: [pc 4] [ot 9] [ab 5]\n",
+            "Box 0: [rn 1] [cm 2]\nBox 3: [ot 9] [ab 5]\n",
+            "Box 0: [rn 1] [cm 2]\nBox 3: [ot 9] [ab 5] [pc 6]\n",
+            "Box 0: [rn 1] [cm 2]\nBox 3: [ot 7] [ab 5] [pc 6]\n",
+        ];
+
+        let input = unwrap_lines(INPUT);
+        let mut boxes = Boxes::default();
+        for (s, expected) in steps(&input).zip(expected) {
+            boxes.apply(assert_ok!(Step::parse(s)));
+            assert_eq!(expected, boxes.to_string(), "after {s}");
+        }
+
+        let labels: Vec<_> = boxes.lenses(3).iter().map(|l| l.label).collect();
+        assert_eq!(vec!["ot", "ab", "pc"], labels);
+    }
+
+    #[test]
+    fn invalid_step() {
+        assert!(Step::parse("rn").is_err());
+        assert!(Step::parse("rn=x").is_err());
+    }
 }
@@ -1,20 +1,176 @@
 use crate::Solver;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
+use rayon::prelude::*;
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        let contraption = Contraption::parse(input)?;
+        let energized = contraption.energized((0, 0), Direction::Right);
+        Ok(energized.to_string())
     }
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_2(&self, input: &str) -> Result<String> {
+        let contraption = Contraption::parse(input)?;
+        let max = contraption
+            .edge_beams()
+            .into_par_iter()
+            .map(|(position, direction)| contraption.energized(position, direction))
+            .max()
+            .ok_or_else(|| eyre!("the contraption has no edges"))?;
+        Ok(max.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Up = 1,
+    Right = 2,
+    Down = 4,
+    Left = 8,
+}
+
+use Direction::*;
+
+type Position = (usize, usize);
+
+#[derive(Debug)]
+struct Contraption {
+    tiles: Vec<Vec<u8>>,
+    width: usize,
+    height: usize,
+}
+
+impl Contraption {
+    fn parse(input: &str) -> Result<Self> {
+        let tiles: Vec<Vec<u8>> = input
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| l.bytes().collect())
+            .collect();
+
+        let width = tiles.first().map_or(0, |r| r.len());
+        let height = tiles.len();
+        if width == 0 {
+            return Err(eyre!("the contraption has to be a non empty grid"));
+        }
+        if let Some(y) = tiles.iter().position(|r| r.len() != width) {
+            return Err(eyre!("line {} has a different width", y + 1));
+        }
+        if let Some(c) = tiles.iter().flatten().find(|c| !b"./\\|-".contains(c)) {
+            return Err(eyre!("unexpected character {}", *c as char));
+        }
+
+        Ok(Self {
+            tiles,
+            width,
+            height,
+        })
+    }
+
+    /// the directions a beam leaves a tile in, after entering it heading `direction`
+    fn deflect(tile: u8, direction: Direction) -> &'static [Direction] {
+        match (tile, direction) {
+            (b'/', Up) => &[Right],
+            (b'/', Right) => &[Up],
+            (b'/', Down) => &[Left],
+            (b'/', Left) => &[Down],
+            (b'\\', Up) => &[Left],
+            (b'\\', Right) => &[Down],
+            (b'\\', Down) => &[Right],
+            (b'\\', Left) => &[Up],
+            (b'|', Left | Right) => &[Up, Down],
+            (b'-', Up | Down) => &[Left, Right],
+            (_, Up) => &[Up],
+            (_, Right) => &[Right],
+            (_, Down) => &[Down],
+            (_, Left) => &[Left],
+        }
+    }
+
+    fn step(&self, (x, y): Position, direction: Direction) -> Option<Position> {
+        let next = match direction {
+            Up => (x, y.checked_sub(1)?),
+            Right => (x + 1, y),
+            Down => (x, y + 1),
+            Left => (x.checked_sub(1)?, y),
+        };
+        (next.0 < self.width && next.1 < self.height).then_some(next)
+    }
+
+    /// follows the beam entering at `start`, a tile crossed in the same direction twice is a loop
+    fn energized(&self, start: Position, direction: Direction) -> usize {
+        let mut visited = vec![vec![0u8; self.width]; self.height];
+        let mut beams = vec![(start, direction)];
+
+        while let Some(((x, y), direction)) = beams.pop() {
+            if visited[y][x] & direction as u8 != 0 {
+                continue;
+            }
+            visited[y][x] |= direction as u8;
+
+            for &d in Self::deflect(self.tiles[y][x], direction) {
+                if let Some(next) = self.step((x, y), d) {
+                    beams.push((next, d));
+                }
+            }
+        }
+
+        visited.iter().flatten().filter(|&&v| v != 0).count()
+    }
+
+    fn edge_beams(&self) -> Vec<(Position, Direction)> {
+        let (w, h) = (self.width, self.height);
+        let columns = (0..w).flat_map(|x| [((x, 0), Down), ((x, h - 1), Up)]);
+        let rows = (0..h).flat_map(|y| [((0, y), Right), ((w - 1, y), Left)]);
+        columns.chain(rows).collect()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use assert_ok::assert_ok;
+
+    const INPUT: &str = r#".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|...."#;
+
+    #[test]
+    fn part_1() {
+        let energized = assert_ok!(Day.part_1(INPUT));
+        assert_eq!("46", energized)
+    }
+
+    #[test]
+    fn part_2() {
+        let energized = assert_ok!(Day.part_2(INPUT));
+        assert_eq!("51", energized)
+    }
+
+    #[test]
+    fn best_entry() {
+        let contraption = assert_ok!(Contraption::parse(INPUT));
+        assert_eq!(51, contraption.energized((3, 0), Down));
+    }
+
+    #[test]
+    fn loops_terminate() {
+        let contraption = assert_ok!(Contraption::parse("-\\\n\\/"));
+        assert_eq!(4, contraption.energized((0, 0), Right));
+    }
+
+    #[test]
+    fn empty() {
+        assert!(Contraption::parse("").is_err());
+        assert!(Day.part_1("\n").is_err());
+    }
 }
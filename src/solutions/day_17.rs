@@ -1,20 +1,246 @@
+use std::ops::RangeInclusive;
+
 use crate::Solver;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
+use pathfinding::prelude::dijkstra;
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(solve(input, 1..=3)?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(solve(input, 4..=10)?.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+use Direction::*;
+
+impl Direction {
+    fn turns(self) -> [Direction; 2] {
+        match self {
+            Up | Down => [Left, Right],
+            Left | Right => [Up, Down],
+        }
     }
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn arrow(self) -> char {
+        match self {
+            Up => '^',
+            Right => '>',
+            Down => 'v',
+            Left => '<',
+        }
     }
 }
 
+type Position = (usize, usize);
+
+/// where the crucible is, which way it is heading and for how many blocks it did so already
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    position: Position,
+    heading: Option<Direction>,
+    run: usize,
+}
+
+#[derive(Debug)]
+struct City {
+    heat_loss: Vec<Vec<u32>>,
+    width: usize,
+    height: usize,
+}
+
+impl City {
+    fn parse(input: &str) -> Result<Self> {
+        let heat_loss: Vec<Vec<u32>> = input
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| {
+                l.chars()
+                    .map(|c| c.to_digit(10).ok_or_else(|| eyre!("{c} is not a digit")))
+                    .collect::<Result<_>>()
+            })
+            .try_collect()?;
+
+        let width = heat_loss.first().map_or(0, |r| r.len());
+        let height = heat_loss.len();
+        if width == 0 || heat_loss.iter().any(|r| r.len() != width) {
+            return Err(eyre!("the city has to be a non empty rectangle"));
+        }
+
+        Ok(Self {
+            heat_loss,
+            width,
+            height,
+        })
+    }
+
+    fn step(&self, (x, y): Position, direction: Direction) -> Option<Position> {
+        let next = match direction {
+            Up => (x, y.checked_sub(1)?),
+            Right => (x + 1, y),
+            Down => (x, y + 1),
+            Left => (x.checked_sub(1)?, y),
+        };
+        (next.0 < self.width && next.1 < self.height).then_some(next)
+    }
+
+    fn successors(&self, state: &State, run: &RangeInclusive<usize>) -> Vec<(State, u32)> {
+        let directions = match state.heading {
+            None => vec![Up, Right, Down, Left],
+            Some(h) => {
+                let mut d = vec![];
+                if state.run < *run.end() {
+                    d.push(h);
+                }
+                if state.run >= *run.start() {
+                    d.extend(h.turns());
+                }
+                d
+            }
+        };
+
+        directions
+            .into_iter()
+            .filter_map(|d| {
+                let position = self.step(state.position, d)?;
+                let run = match state.heading {
+                    Some(h) if h == d => state.run + 1,
+                    _ => 1,
+                };
+                let next = State {
+                    position,
+                    heading: Some(d),
+                    run,
+                };
+                Some((next, self.heat_loss[position.1][position.0]))
+            })
+            .collect()
+    }
+
+    /// the cheapest way from the top left to the bottom right block,
+    /// never going fewer than `run.start()` or more than `run.end()` blocks straight
+    fn best_path(&self, run: RangeInclusive<usize>) -> Option<(Vec<State>, u32)> {
+        let start = State {
+            position: (0, 0),
+            heading: None,
+            run: 0,
+        };
+        let end = (self.width - 1, self.height - 1);
+
+        dijkstra(
+            &start,
+            |s| self.successors(s, &run),
+            // a city of a single block is done before moving at all
+            |s| s.position == end && (s.heading.is_none() || s.run >= *run.start()),
+        )
+    }
+
+    /// the city map with the path drawn in as arrows
+    fn render(&self, path: &[State]) -> String {
+        let mut map: Vec<Vec<char>> = self
+            .heat_loss
+            .iter()
+            .map(|r| {
+                r.iter()
+                    .map(|&h| char::from_digit(h, 10).unwrap())
+                    .collect()
+            })
+            .collect();
+
+        for s in path.iter() {
+            if let Some(h) = s.heading {
+                map[s.position.1][s.position.0] = h.arrow();
+            }
+        }
+
+        map.into_iter()
+            .map(|r| r.into_iter().chain(['\n']).collect::<String>())
+            .collect()
+    }
+}
+
+fn solve(input: &str, run: RangeInclusive<usize>) -> Result<u32> {
+    let city = City::parse(input)?;
+    let (_, cost) = city
+        .best_path(run.clone())
+        .ok_or_else(|| eyre!("no path with straight runs of {run:?} reaches the end"))?;
+    Ok(cost)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use assert_ok::assert_ok;
+
+    const INPUT: &str = r#"2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533"#;
+
+    const UNFORTUNATE: &str = r#"111111111111
+999999999991
+999999999991
+999999999991
+999999999991"#;
+
+    #[test]
+    fn part_1() {
+        let cost = assert_ok!(solve(INPUT, 1..=3));
+        assert_eq!(102, cost)
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(94, assert_ok!(solve(INPUT, 4..=10)));
+        assert_eq!(71, assert_ok!(solve(UNFORTUNATE, 4..=10)));
+    }
+
+    #[test]
+    fn single_block() {
+        assert_eq!(0, assert_ok!(solve("5", 1..=3)));
+        assert_eq!(0, assert_ok!(solve("5", 4..=10)));
+    }
+
+    #[test]
+    fn path() {
+        let city = assert_ok!(City::parse(UNFORTUNATE));
+        let (path, cost) = city.best_path(4..=10).unwrap();
+
+        let summed: u32 = path
+            .iter()
+            .skip(1)
+            .map(|s| city.heat_loss[s.position.1][s.position.0])
+            .sum();
+        assert_eq!(cost, summed);
+
+        // like in the puzzle text, every block shows the direction the crucible entered it in
+        let expected = r#"1>>>>>>>1111
+9999999v9991
+9999999v9991
+9999999v9991
+9999999v>>>>
+"#;
+        assert_eq!(expected, city.render(&path));
+    }
 }
@@ -1,20 +1,189 @@
 use crate::Solver;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(solve(input, Instruction::plain)?.to_string())
     }
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(solve(input, Instruction::from_color)?.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Instruction {
+    direction: Direction,
+    length: i64,
+}
+
+/// a single dig plan line, `color` is the six hex digits without the `#`
+#[derive(Debug)]
+struct PlanLine<'a> {
+    direction: Direction,
+    length: i64,
+    color: &'a str,
+}
+
+impl Instruction {
+    fn plain(line: &PlanLine<'_>) -> Result<Self> {
+        Ok(Self {
+            direction: line.direction,
+            length: line.length,
+        })
+    }
+
+    /// the first five hex digits are the length, the last one the direction
+    fn from_color(line: &PlanLine<'_>) -> Result<Self> {
+        let (length, direction) = line.color.split_at(5);
+        let length = i64::from_str_radix(length, 16)
+            .map_err(|e| eyre!("invalid length in color {}: {e}", line.color))?;
+        let direction = match direction {
+            "0" => Direction::Right,
+            "1" => Direction::Down,
+            "2" => Direction::Left,
+            "3" => Direction::Up,
+            d => return Err(eyre!("invalid direction {d} in color {}", line.color)),
+        };
+        Ok(Self { direction, length })
+    }
+}
+
+fn solve(input: &str, decode: fn(&PlanLine<'_>) -> Result<Instruction>) -> Result<i64> {
+    let instructions: Vec<Instruction> = input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            let (_, line) =
+                parser::plan_line(l).map_err(|e| eyre!("Error parsing line {l}: {:?}", e))?;
+            decode(&line)
+        })
+        .try_collect()?;
+
+    Ok(lagoon_volume(&instructions))
+}
+
+/// shoelace formula for the area enclosed by the trench's center line, Pick's theorem adds
+/// the half of the one meter wide trench outside of it
+fn lagoon_volume(instructions: &[Instruction]) -> i64 {
+    let (mut x, mut y) = (0i64, 0i64);
+    let mut twice_area = 0;
+    let mut boundary = 0;
+
+    for &Instruction { direction, length } in instructions.iter() {
+        let (nx, ny) = match direction {
+            Direction::Up => (x, y - length),
+            Direction::Right => (x + length, y),
+            Direction::Down => (x, y + length),
+            Direction::Left => (x - length, y),
+        };
+        twice_area += x * ny - nx * y;
+        boundary += length;
+        (x, y) = (nx, ny);
+    }
+
+    let interior = (twice_area.abs() - boundary) / 2 + 1;
+    interior + boundary
+}
+
+mod parser {
+    use super::{Direction, PlanLine};
+    use nom::{
+        branch::alt,
+        bytes::complete::{tag, take_while_m_n},
+        character::complete::{char, digit1, space1},
+        combinator::{map_res, value},
+        sequence::{delimited, tuple},
+        IResult,
+    };
+
+    pub(super) fn plan_line(input: &str) -> IResult<&str, PlanLine<'_>> {
+        let direction = alt((
+            value(Direction::Up, char('U')),
+            value(Direction::Right, char('R')),
+            value(Direction::Down, char('D')),
+            value(Direction::Left, char('L')),
+        ));
+        let color = delimited(
+            tag("(#"),
+            take_while_m_n(6, 6, |c: char| c.is_ascii_hexdigit()),
+            char(')'),
+        );
+
+        let (input, (direction, _, length, _, color)) = tuple((
+            direction,
+            space1,
+            map_res(digit1, str::parse),
+            space1,
+            color,
+        ))(input)?;
+
+        Ok((
+            input,
+            PlanLine {
+                direction,
+                length,
+                color,
+            },
+        ))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use assert_ok::assert_ok;
+
+    const INPUT: &str = r#"R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)"#;
+
+    #[test]
+    fn part_1() {
+        let volume = assert_ok!(solve(INPUT, Instruction::plain));
+        assert_eq!(62, volume)
+    }
+
+    #[test]
+    fn part_2() {
+        let volume = assert_ok!(solve(INPUT, Instruction::from_color));
+        assert_eq!(952408144115, volume)
+    }
+
+    #[test]
+    fn decode_color() {
+        let (_, line) = assert_ok!(parser::plan_line("R 6 (#70c710)"));
+        let instruction = assert_ok!(Instruction::from_color(&line));
+        assert_eq!(
+            Instruction {
+                direction: Direction::Right,
+                length: 461937
+            },
+            instruction
+        );
+
+        let (_, line) = assert_ok!(parser::plan_line("R 6 (#70c714)"));
+        assert!(Instruction::from_color(&line).is_err());
+    }
 }
@@ -1,20 +1,333 @@
+use std::{collections::HashMap, ops::Range};
+
 use crate::Solver;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        let (system, parts) = parse(input)?;
+        let sum: u64 = parts
+            .iter()
+            .filter(|p| system.accepts(p))
+            .map(|p| p.iter().sum::<u64>())
+            .sum();
+        Ok(sum.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        let (system, _) = parse(input)?;
+        let all = [1..4001, 1..4001, 1..4001, 1..4001];
+        Ok(system.count_accepted("in", all).to_string())
+    }
+}
+
+/// the x, m, a and s ratings of a part
+type Part = [u64; 4];
+
+/// a hyper-rectangle of ratings, each half open
+type Ratings = [Range<u64>; 4];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target<'a> {
+    Accept,
+    Reject,
+    Workflow(&'a str),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Less,
+    Greater,
+}
+
+#[derive(Debug)]
+struct Rule<'a> {
+    category: usize,
+    comparison: Comparison,
+    value: u64,
+    target: Target<'a>,
+}
+
+impl<'a> Rule<'a> {
+    fn matches(&self, part: &Part) -> bool {
+        let rating = part[self.category];
+        match self.comparison {
+            Comparison::Less => rating < self.value,
+            Comparison::Greater => rating > self.value,
+        }
     }
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+    /// splits the ratings into the matching and the remaining part, either may be empty
+    fn split(&self, ratings: Ratings) -> (Ratings, Ratings) {
+        let Range { start, end } = ratings[self.category].clone();
+        let (matching, rest) = match self.comparison {
+            Comparison::Less => {
+                let cut = self.value.clamp(start, end);
+                (start..cut, cut..end)
+            }
+            Comparison::Greater => {
+                let cut = (self.value + 1).clamp(start, end);
+                (cut..end, start..cut)
+            }
+        };
+
+        let mut m = ratings.clone();
+        let mut r = ratings;
+        m[self.category] = matching;
+        r[self.category] = rest;
+        (m, r)
+    }
+}
+
+#[derive(Debug)]
+struct Workflow<'a> {
+    rules: Vec<Rule<'a>>,
+    fallback: Target<'a>,
+}
+
+impl<'a> Workflow<'a> {
+    fn targets(&self) -> impl Iterator<Item = Target<'a>> + '_ {
+        self.rules.iter().map(|r| r.target).chain([self.fallback])
+    }
+}
+
+#[derive(Debug)]
+struct System<'a> {
+    workflows: HashMap<&'a str, Workflow<'a>>,
+}
+
+impl<'a> System<'a> {
+    /// every workflow referenced has to exist and no workflow may route back into itself
+    fn new(workflows: Vec<(&'a str, Workflow<'a>)>) -> Result<Self> {
+        let mut map = HashMap::new();
+        for (name, w) in workflows {
+            if map.insert(name, w).is_some() {
+                return Err(eyre!("workflow {name} is defined twice"));
+            }
+        }
+        let system = Self { workflows: map };
+
+        if !system.workflows.contains_key("in") {
+            return Err(eyre!("there is no workflow named in"));
+        }
+        for (name, w) in system.workflows.iter() {
+            for t in w.targets() {
+                if let Target::Workflow(next) = t {
+                    if !system.workflows.contains_key(next) {
+                        return Err(eyre!("workflow {name} routes to unknown workflow {next}"));
+                    }
+                }
+            }
+        }
+
+        let mut state = HashMap::new();
+        for &name in system.workflows.keys() {
+            system.check_cycles(name, &mut state)?;
+        }
+
+        Ok(system)
+    }
+
+    /// depth first search, `false` marks a workflow on the current path, `true` a finished one
+    fn check_cycles(&self, name: &'a str, state: &mut HashMap<&'a str, bool>) -> Result<()> {
+        match state.get(name) {
+            Some(true) => return Ok(()),
+            Some(false) => return Err(eyre!("workflow {name} is part of a cycle")),
+            None => {}
+        }
+        state.insert(name, false);
+        for t in self.workflows[name].targets() {
+            if let Target::Workflow(next) = t {
+                self.check_cycles(next, state)?;
+            }
+        }
+        state.insert(name, true);
+        Ok(())
+    }
+
+    fn accepts(&self, part: &Part) -> bool {
+        let mut name = "in";
+        loop {
+            let w = &self.workflows[name];
+            let target = w
+                .rules
+                .iter()
+                .find(|r| r.matches(part))
+                .map_or(w.fallback, |r| r.target);
+            match target {
+                Target::Accept => return true,
+                Target::Reject => return false,
+                Target::Workflow(next) => name = next,
+            }
+        }
+    }
+
+    /// pushes the whole block of ratings through `name`, splitting it at every rule
+    fn count_accepted(&self, name: &str, ratings: Ratings) -> u64 {
+        let w = &self.workflows[name];
+        let mut rest = ratings;
+        let mut count = 0;
+
+        for r in w.rules.iter() {
+            let (matching, remaining) = r.split(rest);
+            count += self.count_target(r.target, matching);
+            rest = remaining;
+        }
+
+        count + self.count_target(w.fallback, rest)
+    }
+
+    fn count_target(&self, target: Target<'_>, ratings: Ratings) -> u64 {
+        if ratings.iter().any(|r| r.is_empty()) {
+            return 0;
+        }
+        match target {
+            Target::Accept => ratings.iter().map(|r| r.end - r.start).product(),
+            Target::Reject => 0,
+            Target::Workflow(next) => self.count_accepted(next, ratings),
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<(System<'_>, Vec<Part>)> {
+    let (_, (workflows, parts)) =
+        parser::input(input).map_err(|e| eyre!("Error parsing input: {:?}", e))?;
+    Ok((System::new(workflows)?, parts))
+}
+
+mod parser {
+    use super::{Comparison, Part, Rule, Target, Workflow};
+    use nom::{
+        branch::alt,
+        character::complete::{alpha1, char, digit1, line_ending, multispace1, one_of},
+        combinator::{map, map_res, value},
+        multi::{many0, separated_list1},
+        sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+        IResult,
+    };
+
+    type Named<'a> = (&'a str, Workflow<'a>);
+
+    fn category(input: &str) -> IResult<&str, usize> {
+        map(one_of("xmas"), |c| "xmas".find(c).unwrap())(input)
+    }
+
+    fn number(input: &str) -> IResult<&str, u64> {
+        map_res(digit1, str::parse)(input)
+    }
+
+    fn target(input: &str) -> IResult<&str, Target<'_>> {
+        map(alpha1, |t| match t {
+            "A" => Target::Accept,
+            "R" => Target::Reject,
+            name => Target::Workflow(name),
+        })(input)
+    }
+
+    fn rule(input: &str) -> IResult<&str, Rule<'_>> {
+        let comparison = alt((
+            value(Comparison::Less, char('<')),
+            value(Comparison::Greater, char('>')),
+        ));
+        map(
+            tuple((category, comparison, number, preceded(char(':'), target))),
+            |(category, comparison, value, target)| Rule {
+                category,
+                comparison,
+                value,
+                target,
+            },
+        )(input)
+    }
+
+    fn workflow(input: &str) -> IResult<&str, Named<'_>> {
+        let body = pair(many0(terminated(rule, char(','))), target);
+        let (input, (name, (rules, fallback))) =
+            pair(alpha1, delimited(char('{'), body, char('}')))(input)?;
+        Ok((input, (name, Workflow { rules, fallback })))
+    }
+
+    fn part(input: &str) -> IResult<&str, Part> {
+        let rating = |c| preceded(pair(char(c), char('=')), number);
+        map(
+            delimited(
+                char('{'),
+                tuple((
+                    terminated(rating('x'), char(',')),
+                    terminated(rating('m'), char(',')),
+                    terminated(rating('a'), char(',')),
+                    rating('s'),
+                )),
+                char('}'),
+            ),
+            |(x, m, a, s)| [x, m, a, s],
+        )(input)
+    }
+
+    pub(super) fn input(input: &str) -> IResult<&str, (Vec<Named<'_>>, Vec<Part>)> {
+        separated_pair(
+            separated_list1(line_ending, workflow),
+            multispace1,
+            separated_list1(line_ending, part),
+        )(input)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use assert_ok::assert_ok;
+
+    const INPUT: &str = r#"px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}"#;
+
+    #[test]
+    fn part_1() {
+        let sum = assert_ok!(Day.part_1(INPUT));
+        assert_eq!("19114", sum)
+    }
+
+    #[test]
+    fn part_2() {
+        let count = assert_ok!(Day.part_2(INPUT));
+        assert_eq!("167409079868000", count)
+    }
+
+    #[test]
+    fn parser() {
+        let (system, parts) = assert_ok!(parse(INPUT));
+        assert_eq!(11, system.workflows.len());
+        assert_eq!(vec![787, 2655, 1222, 2876], parts[0]);
+        assert_eq!(Target::Workflow("rfg"), system.workflows["px"].fallback);
+    }
+
+    #[test]
+    fn unknown_workflow() {
+        let err = parse("in{x<10:A,nope}\n\n{x=1,m=2,a=3,s=4}").unwrap_err();
+        assert!(err.to_string().contains("nope"));
+    }
+
+    #[test]
+    fn cycle() {
+        let input = "in{x<10:A,ab}\nab{m>5:in,R}\n\n{x=1,m=2,a=3,s=4}";
+        let err = parse(input).unwrap_err();
+        assert!(err.to_string().contains("cycle"));
+    }
 }
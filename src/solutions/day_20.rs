@@ -1,20 +1,308 @@
-use crate::Solver;
-use color_eyre::eyre::Result;
+use std::collections::{HashMap, VecDeque};
+
+use crate::{utils::lcm, Solver};
+use color_eyre::eyre::{eyre, Result};
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        let mut network = Network::parse(input)?;
+        let (mut low, mut high) = (0, 0);
+        for _ in 0..1000 {
+            network.press(|pulse| match pulse.high {
+                true => high += 1,
+                false => low += 1,
+            });
+        }
+        Ok((low * high).to_string())
     }
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_2(&self, input: &str) -> Result<String> {
+        let mut network = Network::parse(input)?;
+        Ok(presses_until_low(&mut network, "rx")?.to_string())
     }
 }
 
+#[derive(Debug, Clone)]
+enum Kind {
+    Broadcaster,
+    FlipFlop {
+        on: bool,
+    },
+    /// remembers the last pulse from each of its inputs
+    Conjunction {
+        memory: Vec<(usize, bool)>,
+    },
+    /// only receives, like `output` or `rx`
+    Sink,
+}
+
+#[derive(Debug, Clone)]
+struct Module {
+    kind: Kind,
+    outputs: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Pulse {
+    from: usize,
+    to: usize,
+    high: bool,
+}
+
+#[derive(Debug)]
+struct Network<'a> {
+    names: Vec<&'a str>,
+    modules: Vec<Module>,
+    broadcaster: usize,
+}
+
+impl<'a> Network<'a> {
+    fn parse(input: &'a str) -> Result<Self> {
+        let mut lines = vec![];
+        for l in input.lines().filter(|l| !l.is_empty()) {
+            let (module, outputs) = l
+                .split_once(" -> ")
+                .ok_or_else(|| eyre!("missing -> in line {l}"))?;
+            let (kind, name) = if let Some(name) = module.strip_prefix('%') {
+                (Kind::FlipFlop { on: false }, name)
+            } else if let Some(name) = module.strip_prefix('&') {
+                (Kind::Conjunction { memory: vec![] }, name)
+            } else if module == "broadcaster" {
+                (Kind::Broadcaster, module)
+            } else {
+                return Err(eyre!("unknown module type in line {l}"));
+            };
+            if name.is_empty() {
+                return Err(eyre!("missing module name in line {l}"));
+            }
+            lines.push((name, kind, outputs.split(", ").collect::<Vec<_>>()));
+        }
+
+        let mut names: Vec<&str> = lines.iter().map(|(n, _, _)| *n).collect();
+        let mut index: HashMap<&str, usize> =
+            names.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+        let mut modules: Vec<Module> = lines
+            .iter()
+            .map(|(_, kind, _)| Module {
+                kind: kind.clone(),
+                outputs: vec![],
+            })
+            .collect();
+
+        for (i, (_, _, outputs)) in lines.iter().enumerate() {
+            for &o in outputs.iter() {
+                let target = *index.entry(o).or_insert_with(|| {
+                    names.push(o);
+                    modules.push(Module {
+                        kind: Kind::Sink,
+                        outputs: vec![],
+                    });
+                    modules.len() - 1
+                });
+                modules[i].outputs.push(target);
+                if let Kind::Conjunction { memory } = &mut modules[target].kind {
+                    memory.push((i, false));
+                }
+            }
+        }
+
+        let broadcaster = *index
+            .get("broadcaster")
+            .ok_or_else(|| eyre!("the network has no broadcaster"))?;
+
+        Ok(Self {
+            names,
+            modules,
+            broadcaster,
+        })
+    }
+
+    fn find(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|&n| n == name)
+    }
+
+    fn inputs(&self, module: usize) -> Vec<usize> {
+        (0..self.modules.len())
+            .filter(|&i| self.modules[i].outputs.contains(&module))
+            .collect()
+    }
+
+    /// pushes the button once, `observe` sees every pulse in the order it is delivered
+    fn press(&mut self, mut observe: impl FnMut(Pulse)) {
+        let mut queue = VecDeque::from([Pulse {
+            from: self.broadcaster,
+            to: self.broadcaster,
+            high: false,
+        }]);
+
+        while let Some(pulse) = queue.pop_front() {
+            observe(pulse);
+            let module = &mut self.modules[pulse.to];
+            let send = match &mut module.kind {
+                Kind::Broadcaster => Some(pulse.high),
+                Kind::FlipFlop { on } => (!pulse.high).then(|| {
+                    *on = !*on;
+                    *on
+                }),
+                Kind::Conjunction { memory } => {
+                    if let Some(m) = memory.iter_mut().find(|(i, _)| *i == pulse.from) {
+                        m.1 = pulse.high;
+                    }
+                    Some(!memory.iter().all(|&(_, high)| high))
+                }
+                Kind::Sink => None,
+            };
+
+            if let Some(high) = send {
+                queue.extend(module.outputs.iter().map(|&to| Pulse {
+                    from: pulse.to,
+                    to,
+                    high,
+                }));
+            }
+        }
+    }
+}
+
+/// the target is fed by a single conjunction, which sends a low pulse once all of its inputs
+/// sent a high one during the same press. that only happens at the lcm of the inputs' first
+/// hits if each of them fires on every multiple of its first hit, which is checked by making
+/// sure the second hit comes at exactly twice the first
+fn presses_until_low(network: &mut Network<'_>, target: &str) -> Result<usize> {
+    const MAX_PRESSES: usize = 100_000;
+
+    let target = network
+        .find(target)
+        .ok_or_else(|| eyre!("the network has no module named {target}"))?;
+    let hub = match network.inputs(target)[..] {
+        [hub] if matches!(network.modules[hub].kind, Kind::Conjunction { .. }) => hub,
+        ref inputs => {
+            return Err(eyre!(
+                "{} is fed by {:?}, expected a single conjunction",
+                network.names[target],
+                inputs.iter().map(|&i| network.names[i]).collect::<Vec<_>>()
+            ))
+        }
+    };
+
+    let inputs = network.inputs(hub);
+    // the presses each input sent a high pulse to the hub on, up to the second one
+    let mut hits: Vec<Vec<usize>> = vec![vec![]; inputs.len()];
+
+    for press in 1..=MAX_PRESSES {
+        network.press(|pulse| {
+            if pulse.to == hub && pulse.high {
+                let i = inputs.iter().position(|&i| i == pulse.from).unwrap();
+                if hits[i].len() < 2 && hits[i].last() != Some(&press) {
+                    hits[i].push(press);
+                }
+            }
+        });
+
+        for (&input, h) in inputs.iter().zip(hits.iter()) {
+            if let [first, second] = h[..] {
+                if second != 2 * first {
+                    return Err(eyre!(
+                        "{} first fired on press {first} and again on {second}, expected a \
+                         cycle starting at the first press",
+                        network.names[input]
+                    ));
+                }
+            }
+        }
+        if hits.iter().all(|h| h.len() == 2) {
+            return Ok(hits.iter().map(|h| h[0]).fold(1, lcm));
+        }
+    }
+
+    Err(eyre!(
+        "not every input of {} sent a high pulse twice within {MAX_PRESSES} presses",
+        network.names[hub]
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use assert_ok::assert_ok;
+    use test_case::test_case;
+
+    const SIMPLE: &str = r#"broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a"#;
+
+    const INTERESTING: &str = r#"broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output"#;
+
+    const COUNTER: &str = r#"broadcaster -> a
+%a -> ia, b
+%b -> ib
+&ia -> hub
+&ib -> hub
+&hub -> rx"#;
+
+    #[test_case(SIMPLE, "32000000")]
+    #[test_case(INTERESTING, "11687500")]
+    fn part_1(input: &str, expected: &str) {
+        let result = assert_ok!(Day.part_1(input));
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn part_2() {
+        let result = assert_ok!(Day.part_2(COUNTER));
+        assert_eq!("4", result)
+    }
+
+    #[test]
+    fn part_2_simulated() {
+        let mut network = assert_ok!(Network::parse(COUNTER));
+        let rx = network.find("rx").unwrap();
+        let mut presses = 0;
+        let mut done = false;
+        while !done {
+            presses += 1;
+            network.press(|p| done |= p.to == rx && !p.high);
+        }
+        assert_eq!(4, presses)
+    }
+
+    #[test]
+    fn single_press() {
+        let mut network = assert_ok!(Network::parse(SIMPLE));
+        let mut pulses = vec![];
+        network.press(|p| pulses.push(p.high));
+        assert_eq!(12, pulses.len());
+        assert_eq!(4, pulses.iter().filter(|&&h| h).count());
+    }
+
+    #[test_case(" -> a"; "empty module")]
+    #[test_case("é -> a"; "non ascii module")]
+    #[test_case("% -> a"; "flip-flop without a name")]
+    fn bad_module(input: &str) {
+        assert!(Network::parse(input).is_err());
+    }
+
+    #[test]
+    fn unexpected_shape() {
+        let err = Day.part_2("broadcaster -> a\n%a -> rx").unwrap_err();
+        assert!(err.to_string().contains("expected a single conjunction"));
+    }
+
+    #[test]
+    fn offset_cycle() {
+        // iia fires on every odd press, so its cycle does not start at the first press
+        let input = "broadcaster -> a\n%a -> ia\n&ia -> iia\n&iia -> hub\n&hub -> rx";
+        let err = Day.part_2(input).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("iia first fired on press 1 and again on 3"));
+    }
 }
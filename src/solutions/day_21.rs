@@ -1,20 +1,198 @@
+use std::collections::VecDeque;
+
 use crate::Solver;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        let garden = Garden::parse(input)?;
+        Ok(garden.reachable(64, Tiling::Finite).to_string())
     }
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_2(&self, input: &str) -> Result<String> {
+        let garden = Garden::parse(input)?;
+        Ok(garden.extrapolate(26501365)?.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tiling {
+    Finite,
+    Infinite,
+}
+
+#[derive(Debug)]
+struct Garden {
+    rocks: Vec<Vec<bool>>,
+    width: usize,
+    height: usize,
+    start: (usize, usize),
+}
+
+impl Garden {
+    fn parse(input: &str) -> Result<Self> {
+        let mut start = None;
+        let mut rocks = vec![];
+        for (y, l) in input.lines().filter(|l| !l.is_empty()).enumerate() {
+            let mut row = vec![];
+            for (x, c) in l.chars().enumerate() {
+                match c {
+                    '#' => row.push(true),
+                    '.' => row.push(false),
+                    'S' => {
+                        start = Some((x, y));
+                        row.push(false)
+                    }
+                    c => return Err(eyre!("unexpected character {c} at {x},{y}")),
+                }
+            }
+            rocks.push(row);
+        }
+
+        let width = rocks.first().map_or(0, |r| r.len());
+        let height = rocks.len();
+        if width == 0 || rocks.iter().any(|r| r.len() != width) {
+            return Err(eyre!("the garden has to be a non empty rectangle"));
+        }
+        let start = start.ok_or_else(|| eyre!("no starting position S in the garden"))?;
+
+        Ok(Self {
+            rocks,
+            width,
+            height,
+            start,
+        })
+    }
+
+    fn is_rock(&self, x: i64, y: i64, tiling: Tiling) -> bool {
+        let (w, h) = (self.width as i64, self.height as i64);
+        match tiling {
+            Tiling::Finite if x < 0 || y < 0 || x >= w || y >= h => true,
+            _ => self.rocks[y.rem_euclid(h) as usize][x.rem_euclid(w) as usize],
+        }
+    }
+
+    /// breadth first search from `S`, a plot reached in `d` steps can be reached again
+    /// in every `d + 2k` steps by stepping back and forth
+    fn reachable(&self, steps: usize, tiling: Tiling) -> usize {
+        let size = 2 * steps + 1;
+        let mut distance = vec![usize::MAX; size * size];
+        let index =
+            |dx: i64, dy: i64| (dy + steps as i64) as usize * size + (dx + steps as i64) as usize;
+
+        let (sx, sy) = (self.start.0 as i64, self.start.1 as i64);
+        let mut queue = VecDeque::from([(0i64, 0i64, 0usize)]);
+        distance[index(0, 0)] = 0;
+        let mut count = 0;
+
+        while let Some((dx, dy, d)) = queue.pop_front() {
+            if d % 2 == steps % 2 {
+                count += 1;
+            }
+            if d == steps {
+                continue;
+            }
+            for (nx, ny) in [(dx + 1, dy), (dx - 1, dy), (dx, dy + 1), (dx, dy - 1)] {
+                if self.is_rock(sx + nx, sy + ny, tiling) || distance[index(nx, ny)] != usize::MAX {
+                    continue;
+                }
+                distance[index(nx, ny)] = d + 1;
+                queue.push_back((nx, ny, d + 1));
+            }
+        }
+
+        count
+    }
+
+    /// on the real input `S` sits in the middle and its row and column are free of rocks, so
+    /// the reachable area grows quadratically in the number of garden repetitions crossed;
+    /// sample three of them and extrapolate with newton's forward differences
+    fn extrapolate(&self, steps: usize) -> Result<usize> {
+        if self.width != self.height {
+            return Err(eyre!("extrapolation needs a square garden"));
+        }
+        let size = self.width;
+        let (offset, repetitions) = (steps % size, steps / size);
+        if repetitions < 2 {
+            return Ok(self.reachable(steps, Tiling::Infinite));
+        }
+
+        let (sx, sy) = self.start;
+        if (sx, sy) != (size / 2, size / 2) {
+            return Err(eyre!(
+                "extrapolation needs S in the middle, found it at {sx},{sy}"
+            ));
+        }
+        if self.rocks[sy].iter().any(|&r| r) || self.rocks.iter().any(|row| row[sx]) {
+            return Err(eyre!(
+                "extrapolation needs the row and column of S free of rocks"
+            ));
+        }
+
+        let [a, b, c] =
+            [0, 1, 2].map(|k| self.reachable(offset + k * size, Tiling::Infinite) as i64);
+        let (d1, d2) = (b - a, c - 2 * b + a);
+        let k = repetitions as i64;
+        Ok((a + k * d1 + k * (k - 1) / 2 * d2) as usize)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use assert_ok::assert_ok;
+    use test_case::test_case;
+
+    const INPUT: &str = r#"...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+..........."#;
+
+    #[test]
+    fn part_1() {
+        let garden = assert_ok!(Garden::parse(INPUT));
+        assert_eq!(16, garden.reachable(6, Tiling::Finite))
+    }
+
+    #[test_case(6, 16)]
+    #[test_case(10, 50)]
+    #[test_case(50, 1594)]
+    #[test_case(100, 6536)]
+    fn infinite(steps: usize, expected: usize) {
+        let garden = assert_ok!(Garden::parse(INPUT));
+        assert_eq!(expected, garden.reachable(steps, Tiling::Infinite))
+    }
+
+    #[test]
+    fn extrapolation() {
+        // an open garden grows as a diamond, so the quadratic holds exactly
+        let garden = assert_ok!(Garden::parse(".....\n.....\n..S..\n.....\n....."));
+        let steps = 2 + 5 * 7;
+        let expected = garden.reachable(steps, Tiling::Infinite);
+        assert_eq!(expected, assert_ok!(garden.extrapolate(steps)));
+    }
+
+    #[test]
+    fn extrapolation_precondition() {
+        // the example has rocks next to S
+        let garden = assert_ok!(Garden::parse(INPUT));
+        let err = garden.extrapolate(5000).unwrap_err();
+        assert!(err.to_string().contains("free of rocks"));
+        assert!(Day.part_2(INPUT).is_err());
+        // too few repetitions to need the quadratic, searched directly
+        assert_eq!(50, assert_ok!(garden.extrapolate(10)));
+
+        let garden = assert_ok!(Garden::parse(".....\n.....\n.S...\n.....\n....."));
+        let err = garden.extrapolate(5000).unwrap_err();
+        assert!(err.to_string().contains("in the middle"));
+    }
 }
@@ -1,20 +1,198 @@
+use std::collections::VecDeque;
+
 use crate::Solver;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        let stack = Stack::settle(parse(input)?);
+        let safe = (0..stack.len()).filter(|&i| stack.is_safe(i)).count();
+        Ok(safe.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        let stack = Stack::settle(parse(input)?);
+        let falls: usize = (0..stack.len()).map(|i| stack.chain_reaction(i)).sum();
+        Ok(falls.to_string())
+    }
+}
+
+/// both corners are inclusive, `start` is never above `end` on any axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Brick {
+    start: [usize; 3],
+    end: [usize; 3],
+}
+
+impl Brick {
+    fn parse(input: &str) -> Result<Self> {
+        let corner = |s: &str| -> Result<[usize; 3]> {
+            let coords: Vec<usize> = s
+                .split(',')
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .map_err(|e| eyre!("invalid coordinate in brick {input}: {e}"))?;
+            coords
+                .try_into()
+                .map_err(|_| eyre!("brick {input} needs three coordinates per corner"))
+        };
+
+        let (a, b) = input
+            .split_once('~')
+            .ok_or_else(|| eyre!("missing ~ in brick {input}"))?;
+        let (a, b) = (corner(a)?, corner(b)?);
+        Ok(Self {
+            start: [0, 1, 2].map(|i| a[i].min(b[i])),
+            end: [0, 1, 2].map(|i| a[i].max(b[i])),
+        })
+    }
+
+    fn footprint(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (self.start[0]..=self.end[0])
+            .flat_map(move |x| (self.start[1]..=self.end[1]).map(move |y| (x, y)))
     }
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn height(&self) -> usize {
+        self.end[2] - self.start[2] + 1
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Brick>> {
+    input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(Brick::parse)
+        .collect()
+}
+
+/// the settled bricks, ordered from the ground up, with who rests on whom
+#[derive(Debug)]
+struct Stack {
+    bricks: Vec<Brick>,
+    supports: Vec<Vec<usize>>,
+    supported_by: Vec<Vec<usize>>,
+}
+
+impl Stack {
+    fn settle(mut bricks: Vec<Brick>) -> Self {
+        bricks.sort_by_key(|b| b.start[2]);
+
+        let width = bricks.iter().map(|b| b.end[0] + 1).max().unwrap_or(0);
+        let depth = bricks.iter().map(|b| b.end[1] + 1).max().unwrap_or(0);
+        // the top z and the brick owning it, for every column
+        let mut heights: Vec<Vec<(usize, Option<usize>)>> = vec![vec![(0, None); depth]; width];
+
+        let mut supports = vec![vec![]; bricks.len()];
+        let mut supported_by: Vec<Vec<usize>> = vec![vec![]; bricks.len()];
+
+        for i in 0..bricks.len() {
+            let brick = bricks[i];
+            let floor = brick
+                .footprint()
+                .map(|(x, y)| heights[x][y].0)
+                .max()
+                .unwrap_or(0);
+
+            for (x, y) in brick.footprint() {
+                if let (z, Some(below)) = heights[x][y] {
+                    if z == floor && !supported_by[i].contains(&below) {
+                        supported_by[i].push(below);
+                        supports[below].push(i);
+                    }
+                }
+            }
+
+            let settled = Brick {
+                start: [brick.start[0], brick.start[1], floor + 1],
+                end: [brick.end[0], brick.end[1], floor + brick.height()],
+            };
+            for (x, y) in settled.footprint() {
+                heights[x][y] = (settled.end[2], Some(i));
+            }
+            bricks[i] = settled;
+        }
+
+        Self {
+            bricks,
+            supports,
+            supported_by,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.bricks.len()
+    }
+
+    /// nothing rests on this brick alone
+    fn is_safe(&self, brick: usize) -> bool {
+        self.supports[brick]
+            .iter()
+            .all(|&above| self.supported_by[above].len() > 1)
+    }
+
+    /// how many other bricks fall once `brick` is gone, a brick falls when all of its
+    /// supporters have fallen
+    fn chain_reaction(&self, brick: usize) -> usize {
+        let mut remaining: Vec<usize> = self.supported_by.iter().map(|s| s.len()).collect();
+        let mut queue = VecDeque::from([brick]);
+        let mut fallen = 0;
+
+        while let Some(b) = queue.pop_front() {
+            for &above in self.supports[b].iter() {
+                remaining[above] -= 1;
+                if remaining[above] == 0 {
+                    fallen += 1;
+                    queue.push_back(above);
+                }
+            }
+        }
+
+        fallen
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use assert_ok::assert_ok;
+
+    const INPUT: &str = r#"1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9"#;
+
+    #[test]
+    fn part_1() {
+        let safe = assert_ok!(Day.part_1(INPUT));
+        assert_eq!("5", safe)
+    }
+
+    #[test]
+    fn part_2() {
+        let falls = assert_ok!(Day.part_2(INPUT));
+        assert_eq!("7", falls)
+    }
+
+    #[test]
+    fn settle() {
+        let stack = Stack::settle(assert_ok!(parse(INPUT)));
+        let tops: Vec<usize> = stack.bricks.iter().map(|b| b.end[2]).collect();
+        assert_eq!(vec![1, 2, 2, 3, 3, 4, 6], tops);
+        // brick A holds up B and C, F holds up G
+        assert_eq!(vec![1, 2], stack.supports[0]);
+        assert_eq!(vec![5], stack.supported_by[6]);
+        assert_eq!(6, stack.chain_reaction(0));
+        assert_eq!(1, stack.chain_reaction(5));
+    }
+
+    #[test]
+    fn invalid_brick() {
+        assert!(Brick::parse("1,0,1-1,2,1").is_err());
+        assert!(Brick::parse("1,0~1,2").is_err());
+    }
 }
@@ -1,20 +1,276 @@
+use std::collections::HashMap;
+
 use crate::Solver;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
+use rayon::prelude::*;
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(solve(input, Slopes::OneWay)?.to_string())
     }
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(solve(input, Slopes::Ignored)?.to_string())
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Slopes {
+    OneWay,
+    Ignored,
+}
+
+type Position = (usize, usize);
+
+const DIRECTIONS: [(isize, isize, u8); 4] =
+    [(0, -1, b'^'), (1, 0, b'>'), (0, 1, b'v'), (-1, 0, b'<')];
+
+#[derive(Debug)]
+struct Trails {
+    tiles: Vec<Vec<u8>>,
+    start: Position,
+    end: Position,
+}
+
+impl Trails {
+    fn parse(input: &str) -> Result<Self> {
+        let tiles: Vec<Vec<u8>> = input
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| l.bytes().collect())
+            .collect();
+
+        let width = tiles.first().map_or(0, |r| r.len());
+        if tiles.len() < 2 || tiles.iter().any(|r| r.len() != width) {
+            return Err(eyre!(
+                "the map has to be a rectangle with at least two rows"
+            ));
+        }
+        if let Some(c) = tiles.iter().flatten().find(|c| !b"#.^>v<".contains(c)) {
+            return Err(eyre!("unexpected character {}", *c as char));
+        }
+
+        let gap = |row: &[u8]| row.iter().position(|&t| t == b'.');
+        let start = gap(&tiles[0]).ok_or_else(|| eyre!("no start in the top row"))?;
+        let end = gap(&tiles[tiles.len() - 1]).ok_or_else(|| eyre!("no end in the bottom row"))?;
+
+        Ok(Self {
+            start: (start, 0),
+            end: (end, tiles.len() - 1),
+            tiles,
+        })
+    }
+
+    fn tile(&self, (x, y): Position) -> u8 {
+        self.tiles[y][x]
+    }
+
+    /// open neighbors, along with the slope that has to be walked downhill to reach them
+    fn neighbors(&self, (x, y): Position) -> impl Iterator<Item = (Position, u8)> + '_ {
+        DIRECTIONS.iter().filter_map(move |&(dx, dy, slope)| {
+            let next = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            let tile = *self.tiles.get(next.1)?.get(next.0)?;
+            (tile != b'#').then_some((next, slope))
+        })
+    }
+
+    fn is_junction(&self, p: Position) -> bool {
+        p == self.start || p == self.end || self.neighbors(p).count() > 2
+    }
+
+    /// stepping onto or off a slope is only allowed downhill
+    fn can_step(&self, from: Position, to: Position, direction: u8, slopes: Slopes) -> bool {
+        let downhill = |t: u8| t == b'.' || t == direction;
+        slopes == Slopes::Ignored || (downhill(self.tile(from)) && downhill(self.tile(to)))
+    }
+
+    /// the trails between junctions collapsed into weighted edges
+    fn graph(&self, slopes: Slopes) -> Result<Graph> {
+        let junctions: Vec<Position> = (0..self.tiles.len())
+            .flat_map(|y| (0..self.tiles[0].len()).map(move |x| (x, y)))
+            .filter(|&p| self.tile(p) != b'#' && self.is_junction(p))
+            .collect();
+        if junctions.len() > 64 {
+            return Err(eyre!(
+                "{} junctions do not fit the visited mask",
+                junctions.len()
+            ));
+        }
+        let index: HashMap<Position, usize> =
+            junctions.iter().enumerate().map(|(i, &p)| (p, i)).collect();
+
+        let mut edges = vec![vec![]; junctions.len()];
+        for (i, &junction) in junctions.iter().enumerate() {
+            'trail: for (first, direction) in self.neighbors(junction) {
+                if !self.can_step(junction, first, direction, slopes) {
+                    continue;
+                }
+                let (mut previous, mut current, mut length) = (junction, first, 1);
+                while !index.contains_key(&current) {
+                    let Some((next, direction)) =
+                        self.neighbors(current).find(|(n, _)| *n != previous)
+                    else {
+                        continue 'trail;
+                    };
+                    if !self.can_step(current, next, direction, slopes) {
+                        continue 'trail;
+                    }
+                    (previous, current, length) = (current, next, length + 1);
+                }
+                edges[i].push((index[&current], length));
+            }
+        }
+
+        let end = index[&self.end];
+        let ways_in = edges.iter().flatten().filter(|(n, _)| *n == end).count();
+        if ways_in != 1 {
+            return Err(eyre!(
+                "the end has to be reached by exactly one trail, found {ways_in}"
+            ));
+        }
+
+        Ok(Graph {
+            edges,
+            start: index[&self.start],
+            end,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Graph {
+    edges: Vec<Vec<(usize, usize)>>,
+    start: usize,
+    end: usize,
+}
+
+impl Graph {
+    /// the longest simple path from `node` to the end, not passing any node in `visited`
+    fn longest(&self, node: usize, visited: u64) -> Option<usize> {
+        if node == self.end {
+            return Some(0);
+        }
+        let visited = visited | 1 << node;
+
+        // the end has a single way in, checked when building the graph, so taking anything else
+        // from there can never reach it
+        if let Some(&(_, length)) = self.edges[node].iter().find(|(n, _)| *n == self.end) {
+            return Some(length);
+        }
+
+        self.edges[node]
+            .iter()
+            .filter(|(next, _)| visited & 1 << next == 0)
+            .filter_map(|&(next, length)| Some(length + self.longest(next, visited)?))
+            .max()
+    }
+
+    /// every simple path prefix of `depth` edges from the start, as (node, visited, length)
+    fn prefixes(&self, depth: usize) -> Vec<(usize, u64, usize)> {
+        let mut frontier = vec![(self.start, 0u64, 0)];
+        for _ in 0..depth {
+            frontier = frontier
+                .into_iter()
+                .flat_map(|(node, visited, length)| {
+                    let visited = visited | 1 << node;
+                    let next: Vec<_> = match node == self.end {
+                        true => vec![(node, visited, length)],
+                        false => self.edges[node]
+                            .iter()
+                            .filter(|(n, _)| visited & 1 << n == 0)
+                            .map(|&(n, l)| (n, visited, length + l))
+                            .collect(),
+                    };
+                    next
+                })
+                .collect();
+        }
+        frontier
+    }
+
+    /// the first few levels are expanded sequentially, the rest of each branch runs in parallel
+    fn longest_path(&self) -> Option<usize> {
+        const SPLIT_DEPTH: usize = 6;
+
+        self.prefixes(SPLIT_DEPTH)
+            .into_par_iter()
+            .filter_map(|(node, visited, length)| Some(length + self.longest(node, visited)?))
+            .max()
+    }
+}
+
+fn solve(input: &str, slopes: Slopes) -> Result<usize> {
+    let graph = Trails::parse(input)?.graph(slopes)?;
+    graph
+        .longest_path()
+        .ok_or_else(|| eyre!("there is no way from the start to the end"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use assert_ok::assert_ok;
+
+    const INPUT: &str = r#"#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#"#;
+
+    #[test]
+    fn part_1() {
+        let length = assert_ok!(solve(INPUT, Slopes::OneWay));
+        assert_eq!(94, length)
+    }
+
+    #[test]
+    fn part_2() {
+        let length = assert_ok!(solve(INPUT, Slopes::Ignored));
+        assert_eq!(154, length)
+    }
+
+    #[test]
+    fn two_ways_into_the_end() {
+        let trails = assert_ok!(Trails::parse("#.###\n#...#\n#.#.#\n#....\n###.."));
+        let err = trails.graph(Slopes::Ignored).unwrap_err();
+        assert!(err.to_string().contains("exactly one trail, found 2"));
+    }
+
+    #[test]
+    fn graph() {
+        let trails = assert_ok!(Trails::parse(INPUT));
+        let graph = assert_ok!(trails.graph(Slopes::Ignored));
+        // start, end and seven crossings
+        assert_eq!(9, graph.edges.len());
+        // the first crossing is 15 steps down the trail, and there is no way back uphill
+        let [(first, 15)] = graph.edges[graph.start][..] else {
+            panic!(
+                "unexpected trail from the start {:?}",
+                graph.edges[graph.start]
+            );
+        };
+        let one_way = assert_ok!(trails.graph(Slopes::OneWay));
+        assert!(graph.edges[first].contains(&(graph.start, 15)));
+        assert!(!one_way.edges[first].contains(&(one_way.start, 15)));
+    }
 }
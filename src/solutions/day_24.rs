@@ -1,20 +1,214 @@
+use std::ops::RangeInclusive;
+
 use crate::Solver;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        let hail = parse(input)?;
+        let area = 200_000_000_000_000..=400_000_000_000_000;
+        Ok(crossings_in(&hail, area).to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        let rock = throw(&parse(input)?)?;
+        Ok(rock.position.iter().sum::<i128>().to_string())
     }
+}
+
+type Vector = [i128; 3];
+
+fn sub(a: Vector, b: Vector) -> Vector {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a: Vector, b: Vector) -> Option<i128> {
+    (0..3).try_fold(0i128, |acc, i| acc.checked_add(a[i].checked_mul(b[i])?))
+}
+
+fn cross(a: Vector, b: Vector) -> Option<Vector> {
+    let c = |i: usize, j: usize| a[i].checked_mul(b[j])?.checked_sub(a[j].checked_mul(b[i])?);
+    Some([c(1, 2)?, c(2, 0)?, c(0, 1)?])
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Hailstone {
+    position: Vector,
+    velocity: Vector,
+}
+
+impl Hailstone {
+    fn parse(input: &str) -> Result<Self> {
+        let vector = |s: &str| -> Result<Vector> {
+            let coords: Vec<i128> = s
+                .split(',')
+                .map(|c| c.trim().parse())
+                .collect::<Result<_, _>>()
+                .map_err(|e| eyre!("invalid number in hailstone {input}: {e}"))?;
+            coords
+                .try_into()
+                .map_err(|_| eyre!("hailstone {input} needs three coordinates per vector"))
+        };
+
+        let (position, velocity) = input
+            .split_once('@')
+            .ok_or_else(|| eyre!("missing @ in hailstone {input}"))?;
+        Ok(Self {
+            position: vector(position)?,
+            velocity: vector(velocity)?,
+        })
+    }
+
+    /// position after `t` nanoseconds
+    fn at(&self, t: i128) -> Option<Vector> {
+        let p = |i: usize| self.position[i].checked_add(self.velocity[i].checked_mul(t)?);
+        Some([p(0)?, p(1)?, p(2)?])
+    }
+
+    /// whether both paths cross in the future within `area` on x and y, ignoring z; parallel
+    /// paths never cross. all comparisons are done on the fractions' numerators, so there is
+    /// no rounding involved
+    fn crosses(&self, other: &Hailstone, area: &RangeInclusive<i128>) -> bool {
+        let ([px, py, _], [vx, vy, _]) = (self.position, self.velocity);
+        let ([qx, qy, _], [wx, wy, _]) = (other.position, other.velocity);
+
+        // px + t vx = qx + s wx and py + t vy = qy + s wy, by cramer's rule
+        let det = wx * vy - vx * wy;
+        if det == 0 {
+            return false;
+        }
+        let (dx, dy) = (qx - px, qy - py);
+        let (t, s) = (wx * dy - wy * dx, vx * dy - vy * dx);
+
+        // normalize to a positive denominator so the inequalities keep their direction
+        let sign = det.signum();
+        let (det, t, s) = (det * sign, t * sign, s * sign);
+        if t < 0 || s < 0 {
+            return false;
+        }
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+        let (x, y) = (px * det + t * vx, py * det + t * vy);
+        let inside = |v: i128| area.start() * det <= v && v <= area.end() * det;
+        inside(x) && inside(y)
     }
 }
 
+fn parse(input: &str) -> Result<Vec<Hailstone>> {
+    input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(Hailstone::parse)
+        .collect()
+}
+
+fn crossings_in(hail: &[Hailstone], area: RangeInclusive<i128>) -> usize {
+    hail.iter()
+        .enumerate()
+        .flat_map(|(i, a)| hail[i + 1..].iter().map(move |b| (a, b)))
+        .filter(|(a, b)| a.crosses(b, &area))
+        .count()
+}
+
+/// seen from the first hailstone, it stands still at the origin and the rock has to pass
+/// through it. the rock's path then lies in the plane through the origin and another
+/// hailstone's path, which makes the time a third hailstone is hit a linear equation. two such
+/// hits pin down the rock, everything is solved over exact integers and checked against the
+/// whole list
+fn throw(hail: &[Hailstone]) -> Result<Hailstone> {
+    let [first, rest @ ..] = hail else {
+        return Err(eyre!("there is no hail to throw at"));
+    };
+    let relative = |h: &Hailstone| Hailstone {
+        position: sub(h.position, first.position),
+        velocity: sub(h.velocity, first.velocity),
+    };
+
+    // the time `b` enters the plane spanned by the origin and `a`'s path
+    let hit = |a: &Hailstone, b: &Hailstone| -> Option<i128> {
+        let normal = cross(a.position, a.velocity)?;
+        let (numerator, denominator) = (dot(b.position, normal)?, dot(b.velocity, normal)?);
+        (denominator != 0 && numerator % denominator == 0).then(|| -numerator / denominator)
+    };
+
+    let rock = |a: &Hailstone, b: &Hailstone| -> Option<Hailstone> {
+        let (ra, rb) = (relative(a), relative(b));
+        let (ta, tb) = (hit(&rb, &ra)?, hit(&ra, &rb)?);
+        let (ca, cb) = (a.at(ta)?, b.at(tb)?);
+        let dt = tb - ta;
+        if dt == 0 || (0..3).any(|i| (cb[i] - ca[i]) % dt != 0) {
+            return None;
+        }
+        let velocity = [0, 1, 2].map(|i| (cb[i] - ca[i]) / dt);
+        let position = [0, 1, 2].map(|i| ca[i] - ta * velocity[i]);
+        Some(Hailstone { position, velocity })
+    };
+
+    let hits = |rock: &Hailstone| {
+        hail.iter().all(|h| {
+            cross(
+                sub(rock.position, h.position),
+                sub(rock.velocity, h.velocity),
+            )
+            .is_some_and(|c| c == [0; 3])
+        })
+    };
+
+    rest.iter()
+        .enumerate()
+        .flat_map(|(i, a)| rest[i + 1..].iter().map(move |b| (a, b)))
+        .find_map(|(a, b)| rock(a, b).filter(hits))
+        .ok_or_else(|| eyre!("no single throw hits every hailstone"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use assert_ok::assert_ok;
+
+    const INPUT: &str = r#"19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3"#;
+
+    #[test]
+    fn part_1() {
+        let hail = assert_ok!(parse(INPUT));
+        assert_eq!(2, crossings_in(&hail, 7..=27))
+    }
+
+    #[test]
+    fn part_2() {
+        let sum = assert_ok!(Day.part_2(INPUT));
+        assert_eq!("47", sum)
+    }
+
+    #[test]
+    fn rock() {
+        let rock = assert_ok!(throw(&assert_ok!(parse(INPUT))));
+        assert_eq!([24, 13, 10], rock.position);
+        assert_eq!([-3, 1, 2], rock.velocity);
+    }
+
+    #[test]
+    fn crossings() {
+        let hail = assert_ok!(parse(INPUT));
+        // inside, inside, outside, in the past for one of them
+        assert!(hail[0].crosses(&hail[1], &(7..=27)));
+        assert!(hail[0].crosses(&hail[2], &(7..=27)));
+        assert!(!hail[0].crosses(&hail[3], &(7..=27)));
+        assert!(!hail[0].crosses(&hail[4], &(7..=27)));
+        // parallel paths
+        assert!(!hail[1].crosses(&hail[2], &(7..=27)));
+    }
+
+    #[test]
+    fn no_throw() {
+        let hail = assert_ok!(parse(
+            "0, 0, 0 @ 1, 0, 0\n0, 1, 0 @ 1, 0, 0\n0, 0, 1 @ 1, 0, 0"
+        ));
+        assert!(throw(&hail).is_err());
+    }
 }
@@ -1,20 +1,244 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Display,
+};
+
 use crate::Solver;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        let cut = Graph::parse(input)?.cut(3)?;
+        Ok((cut.sizes.0 * cut.sizes.1).to_string())
     }
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+    /// the last star is given away for having all the others
+    fn part_2(&self, _input: &str) -> Result<String> {
+        Ok("no part 2".to_string())
+    }
+}
+
+#[derive(Debug)]
+struct Graph<'a> {
+    names: Vec<&'a str>,
+    /// both ends of every wire
+    wires: Vec<(usize, usize)>,
+    /// the neighbor and the wire leading to it, for every component
+    adjacent: Vec<Vec<(usize, usize)>>,
+}
+
+/// the wires cut, and how many components end up on either side
+#[derive(Debug)]
+struct Cut<'a> {
+    wires: Vec<(&'a str, &'a str)>,
+    sizes: (usize, usize),
+}
+
+impl<'a> Display for Cut<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let wires: Vec<String> = self.wires.iter().map(|(a, b)| format!("{a}/{b}")).collect();
+        write!(
+            f,
+            "{} splits {} from {}",
+            wires.join(", "),
+            self.sizes.0,
+            self.sizes.1
+        )
+    }
+}
+
+impl<'a> Graph<'a> {
+    fn parse(input: &'a str) -> Result<Self> {
+        let mut names = vec![];
+        let mut index = HashMap::new();
+        let mut id = |name: &'a str| {
+            *index.entry(name).or_insert_with(|| {
+                names.push(name);
+                names.len() - 1
+            })
+        };
+
+        let mut wires = vec![];
+        for l in input.lines().filter(|l| !l.is_empty()) {
+            let (from, to) = l
+                .split_once(": ")
+                .ok_or_else(|| eyre!("missing : in line {l}"))?;
+            let from = id(from);
+            wires.extend(to.split_whitespace().map(|t| (from, id(t))));
+        }
+
+        if names.len() < 2 {
+            return Err(eyre!(
+                "there have to be at least two components, found {}",
+                names.len()
+            ));
+        }
+
+        let mut adjacent = vec![vec![]; names.len()];
+        for (w, &(a, b)) in wires.iter().enumerate() {
+            adjacent[a].push((b, w));
+            adjacent[b].push((a, w));
+        }
+
+        Ok(Self {
+            names,
+            wires,
+            adjacent,
+        })
+    }
+
+    fn distances(&self, from: usize) -> Vec<Option<usize>> {
+        let mut distance = vec![None; self.names.len()];
+        distance[from] = Some(0);
+        let mut queue = VecDeque::from([from]);
+        while let Some(n) = queue.pop_front() {
+            for &(next, _) in self.adjacent[n].iter() {
+                if distance[next].is_none() {
+                    distance[next] = Some(distance[n].unwrap() + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+        distance
+    }
+
+    /// edmonds-karp with every wire carrying one unit in either direction, `flow[w]` is positive
+    /// when it runs from the wire's first end to its second. pushes at most `limit + 1` units,
+    /// and returns the components still reachable from `source` in the residual graph
+    fn max_flow(&self, source: usize, sink: usize, limit: usize) -> (usize, Vec<bool>) {
+        let mut flow = vec![0i8; self.wires.len()];
+        let residual = |flow: &[i8], from: usize, w: usize| match self.wires[w].0 == from {
+            true => 1 - flow[w],
+            false => 1 + flow[w],
+        };
+
+        let mut pushed = 0;
+        loop {
+            let mut previous: Vec<Option<(usize, usize)>> = vec![None; self.names.len()];
+            let mut reached = vec![false; self.names.len()];
+            reached[source] = true;
+            let mut queue = VecDeque::from([source]);
+
+            while let Some(n) = queue.pop_front() {
+                for &(next, w) in self.adjacent[n].iter() {
+                    if !reached[next] && residual(&flow, n, w) > 0 {
+                        reached[next] = true;
+                        previous[next] = Some((n, w));
+                        queue.push_back(next);
+                    }
+                }
+            }
+
+            if !reached[sink] || pushed > limit {
+                return (pushed, reached);
+            }
+
+            let mut n = sink;
+            while let Some((p, w)) = previous[n] {
+                flow[w] += if self.wires[w].0 == p { 1 } else { -1 };
+                n = p;
+            }
+            pushed += 1;
+        }
+    }
+
+    /// the first component is kept on one side, the others are tried as the opposite side
+    /// farthest first, as they are the most likely to be across the cut
+    fn cut(&self, wires: usize) -> Result<Cut<'a>> {
+        let source = 0;
+        let distance = self.distances(source);
+        if distance.iter().any(Option::is_none) {
+            return Err(eyre!("the components are not all connected to begin with"));
+        }
+        let mut sinks: Vec<usize> = (1..self.names.len()).collect();
+        sinks.sort_by_key(|&n| std::cmp::Reverse(distance[n]));
+
+        for sink in sinks {
+            let (flow, side) = self.max_flow(source, sink, wires);
+            if flow != wires {
+                continue;
+            }
+
+            let mut cut: Vec<(&str, &str)> = self
+                .wires
+                .iter()
+                .filter(|&&(a, b)| side[a] != side[b])
+                .map(|&(a, b)| {
+                    let (a, b) = (self.names[a], self.names[b]);
+                    (a.min(b), a.max(b))
+                })
+                .collect();
+            cut.sort();
+
+            let inside = side.iter().filter(|&&s| s).count();
+            return Ok(Cut {
+                wires: cut,
+                sizes: (inside, self.names.len() - inside),
+            });
+        }
+
+        Err(eyre!(
+            "no way to split the components by cutting {wires} wires"
+        ))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use assert_ok::assert_ok;
+
+    const INPUT: &str = r#"jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr"#;
+
+    #[test]
+    fn part_1() {
+        let product = assert_ok!(Day.part_1(INPUT));
+        assert_eq!("54", product)
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!("no part 2", assert_ok!(Day.part_2(INPUT)))
+    }
+
+    #[test]
+    fn wires() {
+        let graph = assert_ok!(Graph::parse(INPUT));
+        assert_eq!(15, graph.names.len());
+        assert_eq!(33, graph.wires.len());
+
+        let cut = assert_ok!(graph.cut(3));
+        assert_eq!(
+            vec![("bvb", "cmg"), ("hfx", "pzl"), ("jqt", "nvd")],
+            cut.wires
+        );
+        assert_eq!("bvb/cmg, hfx/pzl, jqt/nvd splits 6 from 9", cut.to_string());
+    }
+
+    #[test]
+    fn too_few_components() {
+        assert!(Graph::parse("").is_err());
+        assert!(Day.part_1("\n").is_err());
+        assert!(Graph::parse("a: a").is_err());
+    }
+
+    #[test]
+    fn no_cut() {
+        let graph = assert_ok!(Graph::parse("a: b c d e\nb: c d e\nc: d e\nd: e"));
+        assert!(graph.cut(3).is_err());
+    }
 }
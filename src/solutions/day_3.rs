@@ -1,5 +1,5 @@
 use core::borrow;
-use std::{fmt::Display, ops::Range};
+use std::{fmt::Display, ops::Range, sync::LazyLock};
 
 use color_eyre::eyre::eyre;
 use pathfinding::num_traits::Num;
@@ -10,6 +10,8 @@ use color_eyre::eyre::Result;
 
 pub struct Day;
 
+static NUMBER_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"(\d+)"#).unwrap());
+
 impl Solver for Day {
     fn part_1(&self, input: &str) -> Result<String> {
         let schem = Schematic::create(input);
@@ -19,7 +21,9 @@ impl Solver for Day {
     }
 
     fn part_2(&self, input: &str) -> Result<String> {
-        todo!()
+        let schem = Schematic::create(input);
+        let sum = sum_gear_ratios(&schem);
+        Ok(sum.to_string())
     }
 }
 
@@ -45,6 +49,12 @@ struct Number {
     end: usize,
 }
 
+impl Number {
+    fn is_adjacent_to(&self, line: usize, column: usize) -> bool {
+        self.line.abs_diff(line) <= 1 && column + 1 >= self.start && column <= self.end
+    }
+}
+
 impl Display for Number {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let Self { line, start, end } = self;
@@ -61,25 +71,40 @@ impl<'a> Schematic<'a> {
     }
 
     fn get_numbers(&self) -> Vec<Number> {
-        let mut v = vec![];
-        let re = Regex::new(r#"(\d+)"#).unwrap();
+        (0..self.size.1)
+            .flat_map(|i| self.numbers_in_line(i))
+            .collect()
+    }
 
-        for (i, &l) in self.data.iter().enumerate() {
-            for c in re.captures_iter(l) {
-                if let Some(m) = c.get(1) {
-                    let range = m.range();
+    fn numbers_in_line(&self, line: usize) -> Vec<Number> {
+        let Some(&l) = self.data.get(line) else {
+            return vec![];
+        };
+        NUMBER_RE
+            .find_iter(l)
+            .map(|m| Number {
+                line,
+                start: m.start(),
+                end: m.end(),
+            })
+            .collect()
+    }
 
-                    let n = Number {
-                        line: i,
-                        start: m.start(),
-                        end: m.end(),
-                    };
+    /// positions `(line, column)` of every occurence of `symbol`
+    fn get_symbols(&self, symbol: char) -> Vec<(usize, usize)> {
+        self.data
+            .iter()
+            .enumerate()
+            .flat_map(|(i, l)| l.match_indices(symbol).map(move |(j, _)| (i, j)))
+            .collect()
+    }
 
-                    v.push(n);
-                }
-            }
-        }
-        v
+    /// all numbers touching the cell at `(line, column)`, diagonals included
+    fn numbers_adjacent_to(&self, line: usize, column: usize) -> Vec<Number> {
+        (line.saturating_sub(1)..=line + 1)
+            .flat_map(|i| self.numbers_in_line(i))
+            .filter(|n| n.is_adjacent_to(line, column))
+            .collect()
     }
 
     fn get_value(&self, n: &Number) -> usize {
@@ -118,6 +143,16 @@ fn count_part_numbers(nums: Vec<Number>, schem: Schematic<'_>) -> usize {
     cnt
 }
 
+fn sum_gear_ratios(schem: &Schematic<'_>) -> usize {
+    schem
+        .get_symbols('*')
+        .into_iter()
+        .map(|(line, column)| schem.numbers_adjacent_to(line, column))
+        .filter(|nums| nums.len() == 2)
+        .map(|nums| nums.iter().map(|n| schem.get_value(n)).product::<usize>())
+        .sum()
+}
+
 #[cfg(test)]
 mod tests {
     use assert_ok::assert_ok;
@@ -141,4 +176,22 @@ mod tests {
         let cnt = count_part_numbers(nums, schem);
         assert_eq!(4361, cnt)
     }
+
+    #[test]
+    fn adjacent_numbers() {
+        let schem = Schematic::create(INPUT);
+        let values: Vec<_> = schem
+            .numbers_adjacent_to(1, 3)
+            .iter()
+            .map(|n| schem.get_value(n))
+            .collect();
+        assert_eq!(vec![467, 35], values);
+        assert_eq!(1, schem.numbers_adjacent_to(4, 3).len());
+    }
+
+    #[test]
+    fn part_2() {
+        let schem = Schematic::create(INPUT);
+        assert_eq!(467835, sum_gear_ratios(&schem))
+    }
 }
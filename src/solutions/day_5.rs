@@ -1,4 +1,4 @@
-use crate::{solutions::day_5::parser::parse_input, Solver};
+use crate::{solutions::day_5::parser::parse, Solver};
 use color_eyre::eyre::{eyre, Result};
 
 pub struct Day;
@@ -23,16 +23,53 @@ struct Map<'a> {
 }
 
 impl<'a> Map<'a> {
-    fn transform_all(&self, input: usize) -> usize {
-        let fold = self
-            .ranges
+    /// the first range containing `input` maps it, anything else passes through unchanged
+    fn transform(&self, input: usize) -> usize {
+        self.ranges
             .iter()
-            .filter(|r| r.in_range(input))
-            .fold(input, |mut i, r| r.transform(i));
-        fold
+            .find(|r| r.in_range(input))
+            .map_or(input, |r| r.transform(input))
+    }
+
+    /// source ranges must not overlap, otherwise "the" matching range is ambiguous
+    fn validate(&self) -> Result<()> {
+        let mut sources: Vec<_> = self.ranges.iter().map(Range::source).collect();
+        sources.sort_by_key(|s| s.start);
+
+        match sources.windows(2).find(|w| w[0].end > w[1].start) {
+            Some(w) => Err(eyre!(
+                "{}-to-{} map has overlapping source ranges {:?} and {:?}",
+                self.src,
+                self.dst,
+                w[0],
+                w[1]
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// maps whole intervals at once, splitting them wherever they cross a range boundary
+    fn transform_intervals(&self, input: Vec<Interval>) -> Vec<Interval> {
+        let mut unmapped = input;
+        let mut mapped = vec![];
+
+        for r in self.ranges.iter() {
+            let mut rest = vec![];
+            for iv in unmapped.into_iter() {
+                let (inside, outside) = r.split(iv);
+                mapped.extend(inside.map(|iv| r.transform_interval(iv)));
+                rest.extend(outside);
+            }
+            unmapped = rest;
+        }
+
+        mapped.extend(unmapped);
+        mapped
     }
 }
 
+type Interval = std::ops::Range<usize>;
+
 #[derive(Debug)]
 struct Range {
     dst: usize,
@@ -54,16 +91,38 @@ impl Range {
             input
         }
     }
+
+    fn source(&self) -> Interval {
+        self.src..self.src + self.len
+    }
+
+    /// splits `iv` into the part covered by this range and the parts left of and right of it
+    fn split(&self, iv: Interval) -> (Option<Interval>, impl Iterator<Item = Interval>) {
+        let src = self.source();
+        let start = iv.start.max(src.start);
+        let end = iv.end.min(src.end);
+
+        let inside = (start < end).then_some(start..end);
+        let left = iv.start..iv.end.min(src.start);
+        let right = iv.start.max(src.end)..iv.end;
+
+        let outside = [left, right].into_iter().filter(|iv| !iv.is_empty());
+        (inside, outside)
+    }
+
+    /// expects `iv` to be fully inside the source range
+    fn transform_interval(&self, iv: Interval) -> Interval {
+        iv.start - self.src + self.dst..iv.end - self.src + self.dst
+    }
 }
 
 fn solve_part_1(input: &str) -> Result<usize> {
-    let (_, (seeds, maps)) =
-        parse_input(input).map_err(|e| eyre!("Error parsing input: {:?}", e))?;
+    let (seeds, maps) = parse(input)?;
 
     let min = seeds
         .iter()
         .map(|&s| {
-            let fold = maps.iter().fold(s, |s, m| m.transform_all(s));
+            let fold = maps.iter().fold(s, |s, m| m.transform(s));
             fold
         })
         .min()
@@ -72,26 +131,49 @@ fn solve_part_1(input: &str) -> Result<usize> {
     Ok(min)
 }
 
-use rayon::iter::{ParallelIterator, ParallelBridge};
-
-// this is a brute force appproach, just do it for every number ever
-// it could be drastically improved by building a search over ranges, starting at the end
 fn solve_part_2(input: &str) -> Result<usize> {
-    let (_, (seeds, maps)) =
-        parse_input(input).map_err(|e| eyre!("Error parsing input: {:?}", e))?;
+    if cfg!(feature = "brute_force") {
+        return solve_part_2_brute_force(input);
+    }
+    solve_part_2_intervals(input)
+}
+
+fn solve_part_2_intervals(input: &str) -> Result<usize> {
+    let (seeds, maps) = parse(input)?;
+
+    let seeds = seed_intervals(&seeds).collect();
+
+    let min = maps
+        .iter()
+        .fold(seeds, |ivs, m| m.transform_intervals(ivs))
+        .iter()
+        .map(|iv| iv.start)
+        .min()
+        .ok_or(eyre!("no min element found"))?;
+
+    Ok(min)
+}
 
-    let seeds = seeds
+fn seed_intervals(seeds: &[usize]) -> impl Iterator<Item = Interval> + '_ {
+    seeds
         .iter()
         .step_by(2)
         .zip(seeds.iter().skip(1).step_by(2))
         .map(|(&s, &l)| s..s + l)
-        .flatten();
+}
 
-    let min = seeds.par_bridge()
-        .map(|s| {
-            let fold = maps.iter().fold(s, |s, m| m.transform_all(s));
-            fold
-        })
+use rayon::iter::{ParallelBridge, ParallelIterator};
+
+// this is a brute force appproach, just do it for every number ever
+// only kept around to check the interval mapping against, enable with the `brute_force` feature
+fn solve_part_2_brute_force(input: &str) -> Result<usize> {
+    let (seeds, maps) = parse(input)?;
+
+    let seeds = seed_intervals(&seeds).flatten();
+
+    let min = seeds
+        .par_bridge()
+        .map(|s| maps.iter().fold(s, |s, m| m.transform(s)))
         .min()
         .ok_or(eyre!("no min element found"))?;
 
@@ -145,6 +227,15 @@ mod parser {
         Ok((input, Map { src, dst, ranges }))
     }
 
+    pub(super) fn parse(input: &str) -> Result<(Vec<usize>, Vec<Map<'_>>)> {
+        let (_, (seeds, maps)) =
+            parse_input(input).map_err(|e| eyre!("Error parsing input: {:?}", e))?;
+        for m in maps.iter() {
+            m.validate()?;
+        }
+        Ok((seeds, maps))
+    }
+
     pub(super) fn parse_input(input: &str) -> IResult<&str, (Vec<usize>, Vec<Map>)> {
         let (input, seeds) = terminated(parse_seeds, many1(line_ending))(input)?;
         let (input, maps) = separated_list1(line_ending, parse_map)(input)?;
@@ -158,7 +249,7 @@ mod tests {
     use assert_ok::assert_ok;
 
     use super::{
-        parser::{parse_input, parse_map, parse_seeds},
+        parser::{parse, parse_input, parse_map, parse_seeds},
         *,
     };
     const INPUT: &str = r#"seeds: 79 14 55 13
@@ -202,10 +293,42 @@ humidity-to-location map:
     }
     #[test]
     fn part_2() {
-        let x = assert_ok!(solve_part_2(INPUT));
+        let x = assert_ok!(solve_part_2_intervals(INPUT));
         assert_eq!(x, 46)
     }
 
+    #[test]
+    fn part_2_brute_force() {
+        let x = assert_ok!(solve_part_2_brute_force(INPUT));
+        let y = assert_ok!(solve_part_2_intervals(INPUT));
+        assert_eq!(x, y)
+    }
+
+    #[test]
+    fn interval_split() {
+        let (_, map) = assert_ok!(parse_map("seed-to-soil map:\n50 98 2\n52 50 48"));
+        let mut ivs = map.transform_intervals(vec![45..55, 97..101]);
+        ivs.sort_by_key(|iv| iv.start);
+        assert_eq!(vec![45..50, 50..52, 52..57, 99..100, 100..101], ivs);
+    }
+
+    #[test]
+    fn first_range_wins() {
+        // 5 is moved into the second range's source window, but must not be mapped twice
+        let (_, map) = assert_ok!(parse_map("a-to-b map:\n20 5 1\n30 20 1"));
+        assert_ok!(map.validate());
+        assert_eq!(20, map.transform(5));
+        assert_eq!(30, map.transform(20));
+        assert_eq!(7, map.transform(7));
+    }
+
+    #[test]
+    fn overlapping_ranges() {
+        let input = "seeds: 1 2\n\nseed-to-soil map:\n50 98 2\n52 50 49\n";
+        let err = parse(input).unwrap_err();
+        assert!(err.to_string().contains("seed-to-soil"));
+    }
+
     #[test]
     fn parser() {
         let (input, _maps) = assert_ok!(parse_input(INPUT));
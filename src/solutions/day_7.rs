@@ -1,20 +1,179 @@
 use crate::Solver;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(total_winnings(input, Rules::Standard)?.to_string())
     }
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(total_winnings(input, Rules::Jokers)?.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Rules {
+    Standard,
+    /// `J` is a wildcard for the hand kind, but the weakest card in tie-breaks
+    Jokers,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum HandKind {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+const CARDS: &str = "23456789TJQKA";
+const JOKER: char = 'J';
+
+#[derive(Debug)]
+struct Hand {
+    cards: [char; 5],
+    bid: usize,
+}
+
+impl Hand {
+    fn parse(input: &str) -> Result<Self> {
+        let (_, (cards, bid)) =
+            parser::hand(input).map_err(|e| eyre!("Error parsing hand {input}: {:?}", e))?;
+
+        let cards: Vec<char> = cards.chars().collect();
+        if let Some(c) = cards.iter().find(|c| !CARDS.contains(**c)) {
+            return Err(eyre!("unknown card {c} in hand {input}"));
+        }
+        let cards = cards
+            .try_into()
+            .map_err(|_| eyre!("hand {input} does not have 5 cards"))?;
+
+        Ok(Self { cards, bid })
+    }
+
+    fn kind(&self, rules: Rules) -> HandKind {
+        let mut counts = [0u8; CARDS.len()];
+        let mut jokers = 0;
+        for &c in self.cards.iter() {
+            if rules == Rules::Jokers && c == JOKER {
+                jokers += 1;
+            } else {
+                counts[card_strength(c, Rules::Standard) - 1] += 1;
+            }
+        }
+
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        // the jokers always join the largest group
+        counts[0] += jokers;
+
+        match counts {
+            [5, ..] => HandKind::FiveOfAKind,
+            [4, ..] => HandKind::FourOfAKind,
+            [3, 2, ..] => HandKind::FullHouse,
+            [3, ..] => HandKind::ThreeOfAKind,
+            [2, 2, ..] => HandKind::TwoPair,
+            [2, ..] => HandKind::OnePair,
+            _ => HandKind::HighCard,
+        }
+    }
+
+    /// hands compare by kind first, then card by card from the left
+    fn rank_key(&self, rules: Rules) -> (HandKind, [usize; 5]) {
+        (self.kind(rules), self.cards.map(|c| card_strength(c, rules)))
+    }
+}
+
+fn card_strength(card: char, rules: Rules) -> usize {
+    match (card, rules) {
+        (JOKER, Rules::Jokers) => 0,
+        _ => CARDS.find(card).expect("cards are validated while parsing") + 1,
+    }
+}
+
+fn total_winnings(input: &str, rules: Rules) -> Result<usize> {
+    let mut hands: Vec<Hand> = input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(Hand::parse)
+        .try_collect()?;
+
+    hands.sort_by_cached_key(|h| h.rank_key(rules));
+
+    Ok(hands
+        .iter()
+        .enumerate()
+        .map(|(i, h)| (i + 1) * h.bid)
+        .sum())
+}
+
+mod parser {
+    use nom::{
+        bytes::complete::take,
+        character::complete::{digit1, space1},
+        combinator::map_res,
+        sequence::separated_pair,
+        IResult,
+    };
+
+    pub(super) fn hand(input: &str) -> IResult<&str, (&str, usize)> {
+        separated_pair(take(5usize), space1, map_res(digit1, str::parse))(input)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use assert_ok::assert_ok;
+    use test_case::test_case;
+
+    const INPUT: &str = r#"32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483"#;
+
+    #[test]
+    fn part_1() {
+        let sum = assert_ok!(total_winnings(INPUT, Rules::Standard));
+        assert_eq!(6440, sum)
+    }
+
+    #[test]
+    fn part_2() {
+        let sum = assert_ok!(total_winnings(INPUT, Rules::Jokers));
+        assert_eq!(5905, sum)
+    }
+
+    #[test_case("32T3K", Rules::Standard, HandKind::OnePair)]
+    #[test_case("KTJJT", Rules::Standard, HandKind::TwoPair)]
+    #[test_case("KTJJT", Rules::Jokers, HandKind::FourOfAKind)]
+    #[test_case("QQQJA", Rules::Jokers, HandKind::FourOfAKind)]
+    #[test_case("JJJJJ", Rules::Standard, HandKind::FiveOfAKind)]
+    #[test_case("JJJJJ", Rules::Jokers, HandKind::FiveOfAKind)]
+    #[test_case("J2345", Rules::Jokers, HandKind::OnePair)]
+    #[test_case("2233J", Rules::Jokers, HandKind::FullHouse)]
+    #[test_case("23456", Rules::Jokers, HandKind::HighCard)]
+    fn kind(cards: &str, rules: Rules, expected: HandKind) {
+        let hand = assert_ok!(Hand::parse(&format!("{cards} 1")));
+        assert_eq!(expected, hand.kind(rules))
+    }
+
+    #[test]
+    fn joker_is_weakest() {
+        let jokers = assert_ok!(Hand::parse("JJJJJ 1"));
+        let deuces = assert_ok!(Hand::parse("22222 1"));
+        assert!(jokers.rank_key(Rules::Jokers) < deuces.rank_key(Rules::Jokers));
+        assert!(jokers.rank_key(Rules::Standard) > deuces.rank_key(Rules::Standard));
+    }
+
+    #[test]
+    fn invalid_hand() {
+        assert!(Hand::parse("32X3K 765").is_err());
+        assert!(Hand::parse("32T3 765").is_err());
+    }
 }
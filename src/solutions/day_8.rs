@@ -1,20 +1,335 @@
-use crate::Solver;
-use color_eyre::eyre::Result;
+use std::collections::HashMap;
+
+use crate::{utils::lcm, Solver};
+use color_eyre::eyre::{eyre, Result};
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(solve_part_1(input)?.to_string())
     }
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(solve_part_2(input)?.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Left,
+    Right,
+}
+
+/// the node network with every name replaced by its index into `names`
+#[derive(Debug)]
+struct Network<'a> {
+    instructions: Vec<Direction>,
+    names: Vec<&'a str>,
+    nodes: Vec<(usize, usize)>,
+}
+
+impl<'a> Network<'a> {
+    fn parse(input: &'a str) -> Result<Self> {
+        let (_, (instructions, raw)) =
+            parser::network(input).map_err(|e| eyre!("Error parsing input: {:?}", e))?;
+
+        let names: Vec<&str> = raw.iter().map(|(name, _, _)| *name).collect();
+        let index: HashMap<&str, usize> = names.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+        let lookup = |name: &str| {
+            index
+                .get(name)
+                .copied()
+                .ok_or_else(|| eyre!("node {name} is referenced but never defined"))
+        };
+
+        let nodes = raw
+            .iter()
+            .map(|(_, l, r)| Ok((lookup(l)?, lookup(r)?)))
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            instructions,
+            names,
+            nodes,
+        })
+    }
+
+    fn find(&self, name: &str) -> Result<usize> {
+        self.names
+            .iter()
+            .position(|&n| n == name)
+            .ok_or_else(|| eyre!("no node named {name}"))
+    }
+
+    fn step(&self, node: usize, instruction: usize) -> usize {
+        let (left, right) = self.nodes[node];
+        match self.instructions[instruction % self.instructions.len()] {
+            Direction::Left => left,
+            Direction::Right => right,
+        }
+    }
+
+    /// walks from `start` until the same node is reached at the same instruction again,
+    /// remembering every step at which `is_end` held
+    fn ghost(&self, start: usize, is_end: impl Fn(&str) -> bool) -> Ghost {
+        let len = self.instructions.len();
+        let mut seen = vec![None; self.nodes.len() * len];
+        let mut hits = vec![];
+        let mut node = start;
+        let mut steps = 0;
+
+        loop {
+            let state = node * len + steps % len;
+            if let Some(first) = seen[state] {
+                return Ghost {
+                    hits,
+                    start: first,
+                    period: steps - first,
+                };
+            }
+            seen[state] = Some(steps);
+            if steps > 0 && is_end(self.names[node]) {
+                hits.push(steps);
+            }
+            node = self.step(node, steps);
+            steps += 1;
+        }
+    }
+}
+
+/// the steps at which a single ghost stands on an end node,
+/// every hit at or after `start` repeats each `period` steps
+#[derive(Debug)]
+struct Ghost {
+    hits: Vec<usize>,
+    start: usize,
+    period: usize,
+}
+
+impl Ghost {
+    fn is_done_at(&self, steps: usize) -> bool {
+        let steps = match steps.checked_sub(self.start) {
+            Some(offset) => self.start + offset % self.period,
+            None => steps,
+        };
+        self.hits.binary_search(&steps).is_ok()
+    }
+
+    fn cycle_hits(&self) -> impl Iterator<Item = usize> + '_ {
+        self.hits.iter().copied().filter(|&h| h >= self.start)
+    }
+
+    /// the common case, every ghost hits the end exactly once per cycle, and right at its end
+    fn is_aligned(&self) -> bool {
+        let mut hits = self.cycle_hits();
+        matches!((hits.next(), hits.next()), (Some(h), None) if h % self.period == 0)
+    }
+}
+
+fn solve_part_1(input: &str) -> Result<usize> {
+    let network = Network::parse(input)?;
+    let end = network.find("ZZZ")?;
+    let mut node = network.find("AAA")?;
+    let mut steps = 0;
+
+    while node != end {
+        if steps > network.nodes.len() * network.instructions.len() {
+            return Err(eyre!("ZZZ can not be reached from AAA"));
+        }
+        node = network.step(node, steps);
+        steps += 1;
+    }
+
+    Ok(steps)
+}
+
+fn solve_part_2(input: &str) -> Result<usize> {
+    let network = Network::parse(input)?;
+    let ghosts: Vec<Ghost> = network
+        .names
+        .iter()
+        .enumerate()
+        .filter(|(_, n)| n.ends_with('A'))
+        .map(|(i, _)| network.ghost(i, |n| n.ends_with('Z')))
+        .collect();
+
+    if ghosts.is_empty() {
+        return Err(eyre!("no starting nodes found"));
+    }
+
+    // before every ghost is inside its cycle there is nothing periodic to combine
+    let settled = ghosts.iter().map(|g| g.start).max().unwrap_or(0).max(1);
+    if let Some(steps) = (1..settled).find(|&s| ghosts.iter().all(|g| g.is_done_at(s))) {
+        return Ok(steps);
+    }
+
+    if ghosts.iter().all(Ghost::is_aligned) {
+        let steps = ghosts.iter().map(|g| g.period).fold(1, lcm);
+        if steps >= settled {
+            return Ok(steps);
+        }
+    }
+
+    // every ghost allows a set of residues modulo its period, try all combinations of them
+    let mut candidates = vec![(0i128, 1i128)];
+    for g in ghosts.iter() {
+        let period = g.period as i128;
+        candidates = candidates
+            .into_iter()
+            .flat_map(|c| {
+                g.cycle_hits()
+                    .filter_map(move |h| crt(c, (h as i128, period)))
+            })
+            .collect();
+    }
+
+    candidates
+        .into_iter()
+        .map(|(r, m)| {
+            let settled = settled as i128;
+            let r = r.rem_euclid(m);
+            let steps = if r >= settled {
+                r
+            } else {
+                r + (settled - r).div_ceil(m) * m
+            };
+            steps as usize
+        })
+        .min()
+        .ok_or_else(|| eyre!("the ghosts never all reach an end node at the same time"))
+}
+
+/// returns `(g, x, y)` with `a * x + b * y == g == gcd(a, b)`
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a.rem_euclid(b));
+        (g, y, x - a.div_euclid(b) * y)
+    }
+}
+
+/// combines `t = r1 mod m1` and `t = r2 mod m2`, the moduli do not have to be coprime
+fn crt((r1, m1): (i128, i128), (r2, m2): (i128, i128)) -> Option<(i128, i128)> {
+    let (g, p, _) = extended_gcd(m1, m2);
+    if (r2 - r1) % g != 0 {
+        return None;
+    }
+    let m = m1 / g * m2;
+    let k = ((r2 - r1) / g * p).rem_euclid(m2 / g);
+    Some(((r1 + k * m1).rem_euclid(m), m))
+}
+
+mod parser {
+    use super::Direction;
+    use nom::{
+        branch::alt,
+        bytes::complete::tag,
+        character::complete::{alphanumeric1, char, line_ending, multispace1},
+        combinator::{map, value},
+        multi::{many1, separated_list1},
+        sequence::{delimited, separated_pair, tuple},
+        IResult,
+    };
+
+    type Node<'a> = (&'a str, &'a str, &'a str);
+
+    fn node(input: &str) -> IResult<&str, Node<'_>> {
+        map(
+            separated_pair(
+                alphanumeric1,
+                tag(" = "),
+                delimited(
+                    char('('),
+                    separated_pair(alphanumeric1, tag(", "), alphanumeric1),
+                    char(')'),
+                ),
+            ),
+            |(name, (left, right))| (name, left, right),
+        )(input)
+    }
+
+    pub(super) fn network(input: &str) -> IResult<&str, (Vec<Direction>, Vec<Node<'_>>)> {
+        let directions = many1(alt((
+            value(Direction::Left, char('L')),
+            value(Direction::Right, char('R')),
+        )));
+        let (input, (directions, _, nodes)) =
+            tuple((directions, multispace1, separated_list1(line_ending, node)))(input)?;
+        Ok((input, (directions, nodes)))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use assert_ok::assert_ok;
+
+    const INPUT_1: &str = r#"RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)"#;
+
+    const INPUT_2: &str = r#"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"#;
+
+    const INPUT_3: &str = r#"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)"#;
+
+    #[test]
+    fn part_1() {
+        assert_eq!(2, assert_ok!(solve_part_1(INPUT_1)));
+        assert_eq!(6, assert_ok!(solve_part_1(INPUT_2)));
+    }
+
+    #[test]
+    fn part_2() {
+        let steps = assert_ok!(solve_part_2(INPUT_3));
+        assert_eq!(6, steps)
+    }
+
+    #[test]
+    fn unaligned_cycles() {
+        // the first ghost ends on odd steps, the second on steps 2, 5, 8, ...
+        let input = r#"L
+
+1AA = (1ZZ, 1ZZ)
+1ZZ = (1BB, 1BB)
+1BB = (1ZZ, 1ZZ)
+2AA = (2BB, 2BB)
+2BB = (2ZZ, 2ZZ)
+2ZZ = (2CC, 2CC)
+2CC = (2DD, 2DD)
+2DD = (2ZZ, 2ZZ)"#;
+        let steps = assert_ok!(solve_part_2(input));
+        assert_eq!(5, steps)
+    }
+
+    #[test]
+    fn crt_non_coprime() {
+        assert_eq!(Some((10, 12)), crt((4, 6), (2, 4)));
+        assert_eq!(None, crt((1, 6), (2, 4)));
+    }
+
+    #[test]
+    fn undefined_node() {
+        assert!(Network::parse("L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)").is_err());
+    }
 }
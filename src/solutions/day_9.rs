@@ -1,20 +1,145 @@
 use crate::Solver;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 
 pub struct Day;
 
 impl Solver for Day {
-    fn part_1(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(solve(input, Direction::Forward)?.to_string())
     }
 
-    fn part_2(&self, input: &str) ->  Result<String>  {
-        todo!()
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(solve(input, Direction::Backward)?.to_string())
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Forward,
+    Backward,
+}
+
+/// every history along with the line number it was found on
+fn parse(input: &str) -> Result<Vec<(usize, Vec<i64>)>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.is_empty())
+        .map(|(i, l)| {
+            l.split_whitespace()
+                .map(str::parse)
+                .collect::<Result<Vec<i64>, _>>()
+                .map(|history| (i + 1, history))
+                .map_err(|e| eyre!("failed to parse line {}: {e}", i + 1))
+        })
+        .collect()
+}
+
+fn solve(input: &str, direction: Direction) -> Result<i64> {
+    parse(input)?.iter().try_fold(0i64, |sum, (line, history)| {
+        extrapolate(history, direction)
+            .and_then(|x| sum.checked_add(x))
+            .ok_or_else(|| eyre!("history on line {line} overflowed"))
+    })
+}
+
+/// builds the difference table down to the all zero row and extends it again on the way up
+fn extrapolate(history: &[i64], direction: Direction) -> Option<i64> {
+    let mut row = history.to_vec();
+    let mut edges = vec![];
+
+    while row.iter().any(|&x| x != 0) {
+        edges.push(match direction {
+            Direction::Forward => *row.last()?,
+            Direction::Backward => row[0],
+        });
+        row = row
+            .windows(2)
+            .map(|w| w[1].checked_sub(w[0]))
+            .collect::<Option<_>>()?;
+    }
+
+    edges
+        .iter()
+        .rev()
+        .try_fold(0i64, |below, &edge| match direction {
+            Direction::Forward => edge.checked_add(below),
+            Direction::Backward => edge.checked_sub(below),
+        })
+}
+
+/// the same extrapolation via Lagrange interpolation at `n` (or `-1`),
+/// which for equidistant points collapses to alternating binomial coefficients
+fn extrapolate_closed_form(history: &[i64], direction: Direction) -> Option<i64> {
+    let n = history.len() as i64;
+    let mut binomial = 1i64; // C(n, k), starting at k = 0
+    let mut sum = 0i64;
+
+    for k in 0..n {
+        let (coefficient, value) = match direction {
+            // a_n = sum (-1)^(n-1-k) C(n, k) a_k
+            Direction::Forward => (binomial, history[k as usize]),
+            // a_-1 = sum (-1)^k C(n, k+1) a_k, walked from the back so k+1 runs through n..1
+            Direction::Backward => (binomial, history[(n - 1 - k) as usize]),
+        };
+        let term = coefficient.checked_mul(value)?;
+        sum = if (n - 1 - k) % 2 == 0 {
+            sum.checked_add(term)?
+        } else {
+            sum.checked_sub(term)?
+        };
+        binomial = binomial.checked_mul(n - k)? / (k + 1);
+    }
+
+    Some(sum)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "";
+    use assert_ok::assert_ok;
+    use test_case::test_case;
+
+    const INPUT: &str = r#"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45"#;
+
+    #[test]
+    fn part_1() {
+        let sum = assert_ok!(solve(INPUT, Direction::Forward));
+        assert_eq!(114, sum)
+    }
+
+    #[test]
+    fn part_2() {
+        let sum = assert_ok!(solve(INPUT, Direction::Backward));
+        assert_eq!(2, sum)
+    }
+
+    #[test_case("0 3 6 9 12 15", 18, -3)]
+    #[test_case("1 3 6 10 15 21", 28, 0)]
+    #[test_case("10 13 16 21 30 45", 68, 5)]
+    #[test_case("-4 -9 -14 -19", -24, 1)]
+    #[test_case("7", 7, 7)]
+    fn closed_form_agrees(line: &str, next: i64, previous: i64) {
+        let (_, history) = assert_ok!(parse(line)).remove(0);
+        for (direction, expected) in [(Direction::Forward, next), (Direction::Backward, previous)] {
+            assert_eq!(Some(expected), extrapolate(&history, direction));
+            assert_eq!(Some(expected), extrapolate_closed_form(&history, direction));
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        let input = format!("1 2 3\n0 {} 0", i64::MAX);
+        let err = solve(&input, Direction::Forward).unwrap_err();
+        assert!(err.to_string().contains("line 2"));
+
+        // blank lines still count towards the line number
+        let input = format!("1 2 3\n\n0 {} 0", i64::MAX);
+        let err = solve(&input, Direction::Forward).unwrap_err();
+        assert!(err.to_string().contains("line 3"));
+        let err = solve("1 2 3\n\n1 x", Direction::Forward).unwrap_err();
+        assert!(err.to_string().contains("line 3"));
+    }
 }
@@ -0,0 +1,86 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// a sequence of states that repeats itself after `start` with length `period`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// maps any step `n` onto the equivalent step among the first `start + period` ones
+    pub fn index_of(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+/// applies `step` until a state repeats, returns the cycle and every state seen before the repetition
+pub(crate) fn find_cycle<T, F>(initial: T, mut step: F) -> (Cycle, Vec<T>)
+where
+    T: Hash + Eq + Clone,
+    F: FnMut(&T) -> T,
+{
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut current = initial;
+
+    loop {
+        if let Some(&start) = seen.get(&current) {
+            let period = states.len() - start;
+            return (Cycle { start, period }, states);
+        }
+        seen.insert(current.clone(), states.len());
+        let next = step(&current);
+        states.push(current);
+        current = next;
+    }
+}
+
+/// the state after `n` applications of `step`, skipping ahead once a cycle is found
+pub(crate) fn nth_state<T, F>(initial: T, n: usize, step: F) -> T
+where
+    T: Hash + Eq + Clone,
+    F: FnMut(&T) -> T,
+{
+    let (cycle, mut states) = find_cycle(initial, step);
+    states.swap_remove(cycle.index_of(n))
+}
+
+pub(crate) fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+pub(crate) fn lcm(a: usize, b: usize) -> usize {
+    a / gcd(a, b) * b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycle() {
+        // 0, 1, 2, 3, 4, 2, 3, 4, ...
+        let step = |&x: &usize| if x == 4 { 2 } else { x + 1 };
+        let (cycle, states) = find_cycle(0, step);
+        assert_eq!(Cycle { start: 2, period: 3 }, cycle);
+        assert_eq!(vec![0, 1, 2, 3, 4], states);
+        assert_eq!(1, nth_state(0, 1, step));
+        assert_eq!(4, nth_state(0, 1_000, step));
+    }
+
+    #[test]
+    fn gcd_lcm() {
+        assert_eq!(6, gcd(12, 18));
+        assert_eq!(36, lcm(12, 18));
+        assert_eq!(1, [1, 1].into_iter().fold(1, lcm));
+    }
+}